@@ -0,0 +1,206 @@
+use clap::{Parser, Subcommand};
+use puzzle_input::find_package;
+use puzzle_input::store::{self, Secret};
+use solution::{solve, Part, Solved, Solver};
+use std::path::{Path, PathBuf};
+use std::process::exit;
+use std::str::FromStr;
+use std::{fs, io};
+
+const YEAR: i32 = 2024;
+const LAST_DAY: u32 = 14;
+const INPUT_FILE_NAME: &str = "input.txt";
+const EXAMPLE_FILE_NAME: &str = "example1.txt";
+
+/// Runs the solutions of the days on their input
+#[derive(Parser, Debug)]
+#[command(version, about)]
+struct Args {
+    #[command(subcommand)]
+    command: Commands,
+}
+
+#[derive(Subcommand, Debug)]
+enum Commands {
+    /// Runs the solution of a day, or of every day with `all`
+    Run {
+        /// The day, or `all`
+        day: Days,
+
+        /// Runs only this part (1 or 2)
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+
+        /// Reads the input from this file instead of the input.txt of the day
+        #[arg(long, value_name = "PATH", conflicts_with = "example")]
+        input: Option<PathBuf>,
+
+        /// Runs on the first example of the puzzle, example1.txt of the day
+        #[arg(long, default_value_t = false)]
+        example: bool,
+
+        /// Year of the days, looked for in YEAR/dayN, and in dayN for the 2024 days of this repository
+        #[arg(long, default_value_t = YEAR)]
+        year: i32,
+
+        /// File with the secret of the encrypted inputs, instead of AOC_INPUT_PASSPHRASE
+        #[arg(long, value_name = "PATH")]
+        key_file: Option<PathBuf>,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Days {
+    One(u32),
+    All,
+}
+
+impl FromStr for Days {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, String> {
+        if s == "all" {
+            return Ok(Days::All);
+        }
+        match s.parse() {
+            Ok(day) if (1..=LAST_DAY).contains(&day) => Ok(Days::One(day)),
+            _ => Err(format!("expected a day from 1 to {LAST_DAY} or 'all'")),
+        }
+    }
+}
+
+fn solver(day: u32) -> Option<Solver> {
+    match day {
+        1 => Some(solve::<day1::Day1>),
+        2 => Some(solve::<day2::Day2>),
+        3 => Some(solve::<day3::Day3>),
+        4 => Some(solve::<day4::Day4>),
+        5 => Some(solve::<day5::Day5>),
+        6 => Some(solve::<day6::Day6>),
+        7 => Some(solve::<day7::Day7>),
+        8 => Some(solve::<day8::Day8>),
+        9 => Some(solve::<day9::Day9>),
+        10 => Some(solve::<day10::Day10>),
+        11 => Some(solve::<day11::Day11>),
+        12 => Some(solve::<day12::Day12>),
+        #[cfg(feature = "day13")]
+        13 => Some(solve::<day13::Day13>),
+        14 => Some(solve::<day14::Day14>),
+        _ => None,
+    }
+}
+
+/// Where the input of a day comes from
+enum Source<'a> {
+    File(&'a Path),
+    Input(Option<&'a Secret>),
+    Example,
+}
+
+/// The input of the day, the error is `NotFound` if the day has no package or no input
+fn read_input(year: i32, day: u32, source: &Source) -> io::Result<String> {
+    // the days in the root are the ones of the default year
+    let package = || {
+        find_package(Path::new("."), year, day, Some(YEAR)).ok_or(io::Error::new(
+            io::ErrorKind::NotFound,
+            format!("no package found in {year}/day{day} or day{day}"),
+        ))
+    };
+    let (path, result) = match source {
+        Source::File(path) => (path.to_path_buf(), fs::read_to_string(path)),
+        Source::Input(secret) => {
+            let path = package()?.join(INPUT_FILE_NAME);
+            let result = store::read_input(&path, *secret);
+            (path, result)
+        }
+        Source::Example => {
+            let path = package()?.join(EXAMPLE_FILE_NAME);
+            let result = fs::read_to_string(&path);
+            (path, result)
+        }
+    };
+    result.map_err(|err| {
+        io::Error::new(
+            err.kind(),
+            format!("failed to read '{}': {err}", path.display()),
+        )
+    })
+}
+
+fn print_solved(day: u32, solved: &Solved) {
+    println!("Day {day} (parsed in {:.1?})", solved.parse_time);
+    for answer in &solved.answers {
+        println!(
+            "  Part {}: {} ({:.1?})",
+            answer.part.number(),
+            answer.value,
+            answer.time
+        );
+    }
+}
+
+fn run_day(day: u32, input: &str, part: Option<Part>) -> Result<(), String> {
+    let solver = solver(day).ok_or(format!(
+        "day {day} is not available in this build, enable the 'day{day}' feature"
+    ))?;
+    let solved = solver(input, part).map_err(|err| format!("invalid input, {err}"))?;
+    print_solved(day, &solved);
+    Ok(())
+}
+
+fn main() {
+    let args = Args::parse();
+    let Commands::Run {
+        day,
+        part,
+        input,
+        example,
+        year,
+        key_file,
+    } = args.command;
+
+    let part = part.map(|part| if part == 1 { Part::One } else { Part::Two });
+    let secret = Secret::find(key_file.as_deref());
+    let source = match (&input, example) {
+        (Some(path), _) => Source::File(path),
+        (None, true) => Source::Example,
+        (None, false) => Source::Input(secret.as_ref()),
+    };
+
+    match day {
+        Days::One(day) => {
+            let result = read_input(year, day, &source)
+                .map_err(|err| err.to_string())
+                .and_then(|input| run_day(day, &input, part));
+            if let Err(err) = result {
+                eprintln!("Error: Day {day}: {err}.");
+                exit(1);
+            }
+        }
+        Days::All => {
+            if input.is_some() {
+                eprintln!("Error: --input needs a single day.");
+                exit(1);
+            }
+            let mut failed = 0;
+            for day in 1..=LAST_DAY {
+                // the days without an input are skipped, the other errors are reported at the end
+                let result = match read_input(year, day, &source) {
+                    Err(err) if err.kind() == io::ErrorKind::NotFound => {
+                        println!("Day {day} skipped, no input");
+                        continue;
+                    }
+                    Err(err) => Err(err.to_string()),
+                    Ok(input) => run_day(day, &input, part),
+                };
+                if let Err(err) = result {
+                    eprintln!("Error: Day {day}: {err}.");
+                    failed += 1;
+                }
+            }
+            if failed > 0 {
+                exit(1);
+            }
+        }
+    }
+}
@@ -0,0 +1,84 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
+
+/// A directory of its own for each test, with the packages of the days the test needs
+fn test_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("aoc_cli_{name}_{}", std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+fn add_day(dir: &Path, day: u32, file_name: &str, content: &str) {
+    let package = dir.join(format!("day{day}"));
+    fs::create_dir_all(&package).unwrap();
+    fs::write(package.join("Cargo.toml"), "").unwrap();
+    fs::write(package.join(file_name), content).unwrap();
+}
+
+fn run(dir: &Path, args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(args)
+        .current_dir(dir)
+        .env_remove("AOC_INPUT_PASSPHRASE")
+        .output()
+        .unwrap()
+}
+
+#[test]
+fn test_run_day() {
+    let dir = test_dir("day");
+    add_day(&dir, 11, "input.txt", "125 17\n");
+    add_day(&dir, 11, "example1.txt", "0\n");
+
+    let output = run(&dir, &["run", "11"]);
+    assert!(output.status.success(), "{output:?}");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.starts_with("Day 11"));
+    assert!(stdout.contains("  Part 1: 55312 ("));
+    assert!(stdout.contains("  Part 2: 65601038650482 ("));
+
+    let output = run(&dir, &["run", "11", "--part", "1", "--example"]);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("  Part 1: "));
+    assert!(!stdout.contains("Part 2"));
+
+    fs::write(dir.join("other.txt"), "x").unwrap();
+    let output = run(&dir, &["run", "11", "--input", "other.txt"]);
+    assert_eq!(output.status.code(), Some(1));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("invalid input"));
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn test_run_all() {
+    let dir = test_dir("all");
+    add_day(
+        &dir,
+        1,
+        "input.txt",
+        "3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n",
+    );
+
+    let output = run(&dir, &["run", "all"]);
+    assert!(output.status.success(), "{output:?}");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("  Part 1: 11 ("));
+    assert!(stdout.contains("  Part 2: 31 ("));
+    assert!(stdout.contains("Day 2 skipped, no input"));
+
+    let output = run(&dir, &["run", "all", "--year", "2023"]);
+    assert!(String::from_utf8_lossy(&output.stdout).contains("Day 1 skipped, no input"));
+
+    // an input that can't be read is an error, not a missing input
+    add_day(&dir, 3, "input.txt.enc", "not encrypted");
+    let output = run(&dir, &["run", "all"]);
+    assert_eq!(output.status.code(), Some(1));
+    assert!(String::from_utf8_lossy(&output.stdout).contains("  Part 1: 11 ("));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("Error: Day 3: failed to read"));
+
+    let output = run(&dir, &["run", "15"]);
+    assert!(!output.status.success());
+    fs::remove_dir_all(dir).unwrap();
+}
@@ -0,0 +1,82 @@
+use solution::{ParseError, Solution};
+use std::collections::HashMap;
+
+pub struct Day1 {
+    list1: Vec<i64>,
+    list2: Vec<i64>,
+}
+
+impl Solution for Day1 {
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let mut list1: Vec<i64> = vec![];
+        let mut list2: Vec<i64> = vec![];
+
+        for input in input.lines().filter(|line| !line.trim().is_empty()) {
+            let mut parts = input.split_whitespace();
+            list1.push(
+                parts
+                    .next()
+                    .ok_or(ParseError::new("Missing the first number"))?
+                    .parse()?,
+            );
+            list2.push(
+                parts
+                    .next()
+                    .ok_or(ParseError::new("Missing the second number"))?
+                    .parse()?,
+            );
+        }
+
+        list1.sort();
+        list2.sort();
+        Ok(Self { list1, list2 })
+    }
+
+    /// Total distance
+    fn part1(&self) -> i64 {
+        self.list1
+            .iter()
+            .zip(self.list2.iter())
+            .map(|(a, b)| (a - b).abs())
+            .sum()
+    }
+
+    /// Similarity score
+    fn part2(&self) -> i64 {
+        let mut counter: HashMap<i64, i64> = HashMap::new();
+
+        for &num in &self.list2 {
+            let count = counter.entry(num).or_insert(0);
+            *count += 1;
+        }
+
+        self.list1
+            .iter()
+            .map(|x| x * counter.get(x).unwrap_or(&0))
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example() {
+        let input: &str = "3   4
+4   3
+2   5
+1   3
+3   9
+3   3
+";
+
+        let lists = Day1::parse(input).expect("Invalid input");
+
+        assert_eq!(lists.part1(), 11);
+        assert_eq!(lists.part2(), 31);
+    }
+}
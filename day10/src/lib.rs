@@ -0,0 +1,117 @@
+use grid::{Grid, Point};
+use search::{bfs, Paths};
+use solution::{ParseError, Solution};
+
+pub type Day10 = Map;
+
+pub struct Map {
+    topography: Grid<usize>,
+}
+
+impl Map {
+    fn get_starting_pos(&self) -> Vec<Point> {
+        self.topography.positions(|&height| height == 0).collect()
+    }
+
+    /// Number of summits reachable from each trailhead
+    fn find_trailheads(&self) -> usize {
+        self.get_starting_pos()
+            .into_iter()
+            .map(|start| {
+                self.trailhead_paths(start)
+                    .reached()
+                    .filter(|&(&pos, _)| self.is_summit(pos))
+                    .count()
+            })
+            .sum()
+    }
+
+    /// Number of distinct trails from each trailhead, all of them are shortest paths as the
+    /// height goes up by one at each step
+    fn find_trailheads_all_traills(&self) -> usize {
+        self.get_starting_pos()
+            .into_iter()
+            .map(|start| {
+                let paths = self.trailhead_paths(start);
+                paths
+                    .reached()
+                    .filter(|&(&pos, _)| self.is_summit(pos))
+                    .map(|(pos, _)| paths.count_paths_to(pos))
+                    .sum::<usize>()
+            })
+            .sum()
+    }
+
+    fn is_trail(&self, pos: Point, new_pos: Point) -> bool {
+        self.topography[pos] + 1 == self.topography[new_pos]
+    }
+
+    fn find_neighbors(&self, pos: Point) -> Vec<Point> {
+        self.topography
+            .neighbours4(pos)
+            .filter(|&new_pos| self.is_trail(pos, new_pos))
+            .collect()
+    }
+
+    fn is_summit(&self, pos: Point) -> bool {
+        self.topography[pos] == 9
+    }
+
+    fn trailhead_paths(&self, start: Point) -> Paths<Point, usize> {
+        bfs(start, |&pos| self.find_neighbors(pos))
+    }
+}
+
+impl Solution for Map {
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let input: String = input
+            .lines()
+            .take_while(|line| !line.is_empty())
+            .map(|line| format!("{line}\n"))
+            .collect();
+        let heights = Grid::parse(&input, |c| c)?;
+        if let Some(pos) = heights.find(|c| !c.is_ascii_digit()) {
+            return Err(ParseError::new(format!(
+                "The height at {pos:?} is not a digit"
+            )));
+        }
+        Ok(Map {
+            topography: heights.map(|c| c.to_digit(10).unwrap() as usize),
+        })
+    }
+
+    /// The trailheads count
+    fn part1(&self) -> usize {
+        self.find_trailheads()
+    }
+
+    /// The trailheads count of distinct hiking trails
+    fn part2(&self) -> usize {
+        self.find_trailheads_all_traills()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_trails() {
+        let input: &str = "89010123
+78121874
+87430965
+96549874
+45678903
+32019012
+01329801
+10456732";
+
+        let map = Day10::parse(input).expect("Invalid input");
+
+        assert_eq!(map.part1(), 36);
+        assert_eq!(map.part2(), 81);
+    }
+}
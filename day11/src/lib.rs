@@ -0,0 +1,80 @@
+use solution::{ParseError, Solution};
+use std::collections::HashMap;
+
+pub struct Day11 {
+    stones: Vec<usize>,
+}
+
+impl Day11 {
+    /// Number of stones after blinking the given number of times
+    fn count_after(&self, blinks: usize) -> usize {
+        let mut stones_map: HashMap<usize, usize> = HashMap::new();
+        for &stone in &self.stones {
+            let count = stones_map.entry(stone).or_insert(0);
+            *count += 1;
+        }
+
+        for _ in 0..blinks {
+            let mut new_stones_map: HashMap<usize, usize> = HashMap::new();
+
+            stones_map.iter().for_each(|(&stone, &count)| match stone {
+                0 => {
+                    *new_stones_map.entry(stone + 1).or_insert(0) += count;
+                }
+                _ if (stone.ilog10() + 1) % 2 == 0 => {
+                    let splitter = 10_usize.pow((stone.ilog10() + 1) / 2);
+                    let left_stone = stone / splitter;
+                    let right_stone = stone % splitter;
+
+                    *new_stones_map.entry(left_stone).or_insert(0) += count;
+                    *new_stones_map.entry(right_stone).or_insert(0) += count;
+                }
+                _ => {
+                    *new_stones_map.entry(stone * 2024).or_insert(0) += count;
+                }
+            });
+
+            stones_map = new_stones_map;
+        }
+
+        stones_map.values().sum()
+    }
+}
+
+impl Solution for Day11 {
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let stones: Vec<usize> = input
+            .split_whitespace()
+            .map(|s| s.parse::<usize>())
+            .collect::<Result<_, _>>()?;
+        Ok(Self { stones })
+    }
+
+    /// The total count after blinking 25 times
+    fn part1(&self) -> usize {
+        self.count_after(25)
+    }
+
+    /// The total count after blinking 75 times
+    fn part2(&self) -> usize {
+        self.count_after(75)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stones() {
+        let input: &str = "125 17";
+
+        let stones = Day11::parse(input).expect("Invalid input");
+
+        assert_eq!(stones.part1(), 55312);
+        assert_eq!(stones.part2(), 65601038650482);
+    }
+}
@@ -0,0 +1,204 @@
+use grid::{Dir4, Grid, Point};
+use solution::{ParseError, Solution};
+use std::collections::{HashSet, VecDeque};
+
+#[derive(Clone)]
+struct Plot {
+    kind: char,
+    checked: bool,
+    sides: HashSet<Dir4>,
+}
+
+impl Plot {
+    fn new(kind: char) -> Self {
+        Plot {
+            kind,
+            checked: false,
+            sides: HashSet::new(),
+        }
+    }
+}
+
+pub type Day12 = Garden;
+
+#[derive(Clone)]
+pub struct Garden {
+    map: Grid<Plot>,
+}
+
+impl Garden {
+    fn is_same_type(&mut self, pos0: Point, pos1: Point) -> bool {
+        self.map[pos0].kind == self.map[pos1].kind
+    }
+
+    fn get_free_sides(&self, pos: Point, dir: Dir4) -> HashSet<Dir4> {
+        self.map[pos]
+            .sides
+            .iter()
+            .cloned()
+            .filter(|side| side.is_vertical() != dir.is_vertical())
+            .collect()
+    }
+
+    fn find_neighbors_and_sides(&mut self, pos: Point, allow_free: bool) -> (Vec<Point>, usize) {
+        let mut neighbors: Vec<Point> = vec![];
+        let mut free_sides: HashSet<Dir4> = HashSet::new();
+        for dir in Dir4::ALL {
+            if let Some(new_pos) = self.map.step(pos, dir.step()) {
+                if self.is_same_type(pos, new_pos) && self.is_visited(new_pos) {
+                    free_sides.extend(self.get_free_sides(new_pos, dir));
+                }
+            }
+        }
+
+        for dir in Dir4::ALL {
+            if let Some(new_pos) = self.map.step(pos, dir.step()) {
+                if self.is_same_type(pos, new_pos) {
+                    if !self.is_visited(new_pos) {
+                        neighbors.push(new_pos);
+                    }
+                } else {
+                    self.map[pos].sides.insert(dir);
+                }
+            } else {
+                self.map[pos].sides.insert(dir);
+            }
+        }
+
+        let sides_n = if allow_free {
+            self.map[pos]
+                .sides
+                .iter()
+                .filter(|side| !free_sides.contains(side))
+                .count()
+        } else {
+            self.map[pos].sides.len()
+        };
+
+        (neighbors, sides_n)
+    }
+
+    fn set_visited(&mut self, pos: Point) {
+        self.map[pos].checked = true;
+    }
+
+    fn is_visited(&mut self, pos: Point) -> bool {
+        self.map[pos].checked
+    }
+
+    fn region_bfs(&mut self, start: Point, allow_free: bool) -> (usize, usize) {
+        self.set_visited(start);
+        let mut queue = VecDeque::new();
+        queue.push_back(start);
+
+        let mut perimeter: usize = 0;
+        let mut area: usize = 0;
+        while let Some(pos) = queue.pop_front() {
+            let (neighbors, sides_n) = self.find_neighbors_and_sides(pos, allow_free);
+            perimeter += sides_n;
+            area += 1;
+            for neighbor in neighbors {
+                if !self.is_visited(neighbor) {
+                    self.set_visited(neighbor);
+                    queue.push_back(neighbor);
+                }
+            }
+        }
+        (perimeter, area)
+    }
+
+    fn get_price_region(&mut self, pos: Point, allow_free: bool) -> usize {
+        let (perimeter, area) = self.region_bfs(pos, allow_free);
+        perimeter * area
+    }
+
+    fn get_total_price(&mut self, allow_free: bool) -> usize {
+        let mut total_price = 0;
+        let points: Vec<Point> = self.map.points().collect();
+        for pos in points {
+            if !self.is_visited(pos) {
+                total_price += self.get_price_region(pos, allow_free);
+            }
+        }
+        total_price
+    }
+}
+
+impl Solution for Garden {
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let input: String = input
+            .lines()
+            .take_while(|line| !line.is_empty())
+            .map(|line| format!("{line}\n"))
+            .collect();
+        Ok(Garden {
+            map: Grid::parse(&input, Plot::new)?,
+        })
+    }
+
+    /// The total price
+    fn part1(&self) -> usize {
+        self.clone().get_total_price(false)
+    }
+
+    /// The total price with discount
+    fn part2(&self) -> usize {
+        self.clone().get_total_price(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_total_price() {
+        let input: &str = "AAAA
+BBCD
+BBCC
+EEEC";
+
+        let mut garden = Garden {
+            map: Grid::parse(input, Plot::new).expect("No input given"),
+        };
+
+        assert_eq!(140, garden.get_total_price(false));
+    }
+
+    #[test]
+    fn test_total_price_discount() {
+        let input: &str = "AAAA
+BBCD
+BBCC
+EEEC";
+
+        let mut garden = Garden {
+            map: Grid::parse(input, Plot::new).expect("No input given"),
+        };
+
+        assert_eq!(80, garden.get_total_price(true));
+    }
+
+    #[test]
+    fn test_larger_example() {
+        let input: &str = "RRRRIICCFF
+RRRRIICCCF
+VVRRRCCFFF
+VVRCCCJFFF
+VVVVCJJCFE
+VVIVCCJJEE
+VVIIICJJEE
+MIIIIIJJEE
+MIIISIJEEE
+MMMISSJEEE
+";
+
+        let garden = Day12::parse(input).expect("Invalid input");
+
+        assert_eq!(garden.part1(), 1930);
+        assert_eq!(garden.part2(), 1206);
+    }
+}
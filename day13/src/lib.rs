@@ -0,0 +1,157 @@
+use good_lp::{constraint, default_solver, variable, ProblemVariables, Solution as _, SolverModel};
+use regex::Regex;
+use solution::{ParseError, Solution};
+
+fn get_algebra_solution(
+    const_a: (isize, isize, isize),
+    const_b: (isize, isize, isize),
+) -> Option<(usize, usize)> {
+    let det = const_a.0 * const_b.1 - const_a.1 * const_b.0;
+    if det == 0 {
+        return None;
+    }
+    let a = (const_b.1 * const_a.2 - const_a.1 * const_b.2) as f64 / det as f64;
+    let b = (const_b.2 * const_a.0 - const_a.2 * const_b.0) as f64 / det as f64;
+    if a != a.trunc() || b != b.trunc() {
+        return None;
+    }
+
+    Some((a as usize, b as usize))
+}
+
+fn get_lp_solution(const_a: (i32, i32, i32), const_b: (i32, i32, i32)) -> Option<(i32, i32)> {
+    let mut problem = ProblemVariables::new();
+    let a = problem.add(variable().integer().min(0).max(100));
+    let b = problem.add(variable().integer().min(0).max(100));
+    let solution = problem
+        .minimise(3 * a + b)
+        .using(default_solver)
+        .with(constraint!(const_a.0 * a + const_a.1 * b <= const_a.2))
+        .with(constraint!(const_a.0 * a + const_a.1 * b >= const_a.2))
+        .with(constraint!(const_b.0 * a + const_b.1 * b <= const_b.2))
+        .with(constraint!(const_b.0 * a + const_b.1 * b >= const_b.2))
+        .solve()
+        .ok()?;
+    Some((
+        solution.value(a).round() as i32,
+        solution.value(b).round() as i32,
+    ))
+}
+
+fn parse_coordinates(text: &str, regex: &Regex) -> Option<(i32, i32)> {
+    regex.captures(text).map(|cap| {
+        let x = cap
+            .get(1)
+            .and_then(|m| m.as_str().parse::<i32>().ok())
+            .unwrap_or(0);
+        let y = cap
+            .get(2)
+            .and_then(|m| m.as_str().parse::<i32>().ok())
+            .unwrap_or(0);
+        (x, y)
+    })
+}
+
+/// The equations of a claw machine: X and Y of button A, button B and the prize
+#[derive(Clone, Copy)]
+struct Machine {
+    const_a: (i32, i32, i32),
+    const_b: (i32, i32, i32),
+}
+
+pub struct Day13 {
+    machines: Vec<Machine>,
+}
+
+impl Solution for Day13 {
+    type Answer1 = i32;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let re = Regex::new(r"X\+(\d+), Y\+(\d+)")?;
+        let re_price = Regex::new(r"X=(\d+), Y=(\d+)")?;
+
+        let lines: Vec<&str> = input.lines().collect();
+        let mut machines = vec![];
+        for lines in lines.chunks(4) {
+            if lines[0].is_empty() {
+                break;
+            }
+            let [button_a, button_b, price, ..] = lines else {
+                return Err(ParseError::new("Incomplete claw machine"));
+            };
+
+            let mut const_a: (i32, i32, i32) = (0, 0, 0);
+            let mut const_b: (i32, i32, i32) = (0, 0, 0);
+            if let Some((x1, y1)) = parse_coordinates(button_a, &re) {
+                const_a.0 = x1;
+                const_b.0 = y1;
+            }
+
+            if let Some((x2, y2)) = parse_coordinates(button_b, &re) {
+                const_a.1 = x2;
+                const_b.1 = y2;
+            }
+
+            if let Some((px, py)) = parse_coordinates(price, &re_price) {
+                const_a.2 = px;
+                const_b.2 = py;
+            }
+            machines.push(Machine { const_a, const_b });
+        }
+        Ok(Self { machines })
+    }
+
+    /// The smallest number of tokens
+    fn part1(&self) -> i32 {
+        self.machines
+            .iter()
+            .filter_map(|machine| get_lp_solution(machine.const_a, machine.const_b))
+            .map(|solution| 3 * solution.0 + solution.1)
+            .sum()
+    }
+
+    /// The smallest number of tokens with conversion
+    fn part2(&self) -> usize {
+        self.machines
+            .iter()
+            .filter_map(|&Machine { const_a, const_b }| {
+                let mut const_a = (const_a.0 as isize, const_a.1 as isize, const_a.2 as isize);
+                let mut const_b = (const_b.0 as isize, const_b.1 as isize, const_b.2 as isize);
+                const_a.2 += 10000000000000;
+                const_b.2 += 10000000000000;
+                get_algebra_solution(const_a, const_b)
+            })
+            .map(|solution| 3 * solution.0 + solution.1)
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tokens() {
+        let input: &str = "Button A: X+94, Y+34
+Button B: X+22, Y+67
+Prize: X=8400, Y=5400
+
+Button A: X+26, Y+66
+Button B: X+67, Y+21
+Prize: X=12748, Y=12176
+
+Button A: X+17, Y+86
+Button B: X+84, Y+37
+Prize: X=7870, Y=6450
+
+Button A: X+69, Y+23
+Button B: X+27, Y+71
+Prize: X=18641, Y=10279";
+
+        let machines = Day13::parse(input).expect("Invalid input");
+
+        assert_eq!(machines.part1(), 480);
+        assert_eq!(machines.part2(), 875318608908);
+    }
+}
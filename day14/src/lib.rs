@@ -0,0 +1,158 @@
+use grid::Point;
+use regex::Regex;
+use solution::{ParseError, Solution};
+
+const SECS: isize = 100;
+/// The rows and columns of the space the robots move in
+const SIZE: Point = Point::new(103, 101);
+
+fn is_candidate(grid: &Vec<Vec<i32>>) -> bool {
+    for row in grid {
+        let mut count = 0;
+        for &value in row {
+            if value > 0 {
+                count += 1;
+                if count > 7 {
+                    return true;
+                }
+            } else {
+                count = 0;
+            }
+        }
+    }
+    false
+}
+
+fn get_robot_pos(robot: &Vec<isize>, sec: isize, size: Point) -> Point {
+    let pos = Point::new(robot[1], robot[0]);
+    let vel = Point::new(robot[3], robot[2]);
+    pos.wrapping_add(vel * sec, size)
+}
+pub struct Day14 {
+    robots: Vec<Vec<isize>>,
+    size: Point,
+}
+
+impl Day14 {
+    /// Moves the robots in a space of another size, like the smaller one of the example
+    pub fn with_size(self, size: Point) -> Self {
+        Self { size, ..self }
+    }
+}
+
+impl Solution for Day14 {
+    type Answer1 = usize;
+    type Answer2 = isize;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let re = Regex::new(r"(-)?\d+")?;
+        let mut robots: Vec<Vec<isize>> = vec![];
+
+        for robot_info in input.lines().take_while(|line| !line.is_empty()) {
+            let robot: Vec<isize> = re
+                .captures_iter(robot_info)
+                .map(|cap| cap[0].parse::<isize>())
+                .collect::<Result<_, _>>()?;
+
+            if robot.len() != 4 {
+                return Err(ParseError::new(format!("Invalid robot '{robot_info}'")));
+            }
+            robots.push(robot);
+        }
+        Ok(Self { robots, size: SIZE })
+    }
+
+    /// The safety factor
+    fn part1(&self) -> usize {
+        let mut quadrant_counts = [0, 0, 0, 0];
+        let Point {
+            row: row_n,
+            col: col_n,
+        } = self.size;
+        for robot in &self.robots {
+            let new_pos = get_robot_pos(robot, SECS, self.size);
+            let col_idx = if new_pos.col < col_n / 2 {
+                0
+            } else if new_pos.col != col_n / 2 {
+                1
+            } else {
+                continue;
+            };
+            let row_idx = if new_pos.row < row_n / 2 {
+                0
+            } else if new_pos.row != row_n / 2 {
+                1
+            } else {
+                continue;
+            };
+
+            quadrant_counts[row_idx * 2 + col_idx] += 1;
+        }
+        quadrant_counts.iter().product::<usize>()
+    }
+
+    /// The first second the robots draw the Christmas tree, 0 if they never do. The robots are
+    /// back to their start after `rows * cols` seconds.
+    fn part2(&self) -> isize {
+        let Point {
+            row: row_n,
+            col: col_n,
+        } = self.size;
+        (1..=row_n * col_n)
+            .find(|&sec| {
+                let mut grid: Vec<Vec<i32>> = vec![vec![0; col_n as usize]; row_n as usize];
+                for robot in &self.robots {
+                    let new_pos = get_robot_pos(robot, sec, self.size);
+                    grid[new_pos.row as usize][new_pos.col as usize] += 1;
+                }
+                is_candidate(&grid)
+            })
+            .unwrap_or(0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_safety_factor() {
+        let input: &str = "p=0,4 v=3,-3
+p=6,3 v=-1,-3
+p=10,3 v=-1,2
+p=2,0 v=2,-1
+p=0,0 v=1,3
+p=3,0 v=-2,-2
+p=7,6 v=-1,-3
+p=3,0 v=-1,-2
+p=9,3 v=2,3
+p=7,3 v=-1,2
+p=2,4 v=2,-3
+p=9,5 v=-3,-3";
+
+        let robots = Day14::parse(input)
+            .expect("Invalid input")
+            .with_size(Point::new(7, 11));
+
+        assert_eq!(robots.part1(), 12);
+    }
+
+    #[test]
+    fn test_christmas_tree() {
+        // The robots of the columns 0 to 7 are on the same row only every 7 seconds
+        let input: &str = "p=0,0 v=0,0
+p=1,0 v=0,1
+p=2,0 v=0,2
+p=3,0 v=0,3
+p=4,0 v=0,4
+p=5,0 v=0,5
+p=6,0 v=0,6
+p=7,0 v=0,7";
+
+        let robots = Day14::parse(input)
+            .expect("Invalid input")
+            .with_size(Point::new(7, 11));
+
+        assert_eq!(robots.part2(), 7);
+    }
+}
@@ -0,0 +1,89 @@
+use solution::{ParseError, Solution};
+
+pub struct Day2 {
+    levels: Vec<Vec<i64>>,
+}
+
+impl Solution for Day2 {
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let mut levels = vec![];
+        for input in input.lines().filter(|line| !line.trim().is_empty()) {
+            let parts = input.split_whitespace();
+            let level: Vec<i64> = parts.map(|x| x.parse::<i64>()).collect::<Result<_, _>>()?;
+            levels.push(level);
+        }
+        Ok(Self { levels })
+    }
+
+    /// Safe levels
+    fn part1(&self) -> usize {
+        self.levels.iter().filter(|level| is_safe(level)).count()
+    }
+
+    /// Safe levels with one bad level tolerated
+    fn part2(&self) -> usize {
+        self.levels
+            .iter()
+            .filter(|level| is_safe_one_tolerated(level))
+            .count()
+    }
+}
+
+fn is_safe(level: &Vec<i64>) -> bool {
+    let mut inc = true;
+    for idx in 0..level.len() - 1 {
+        let diff = level[idx + 1] - level[idx];
+        if idx == 0 && diff < 0 {
+            inc = false;
+        }
+        match inc {
+            true => {
+                if diff < 1 || diff > 3 {
+                    return false;
+                }
+            }
+            false => {
+                if diff < -3 || diff > -1 {
+                    return false;
+                }
+            }
+        }
+    }
+    true
+}
+
+fn is_safe_one_tolerated(level: &Vec<i64>) -> bool {
+    for idx in 0..level.len() {
+        let mut tmp_level = level.clone();
+        tmp_level.remove(idx);
+        if is_safe(&tmp_level) {
+            return true;
+        }
+    }
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example() {
+        let input: &str = "7 6 4 2 1
+1 2 7 8 9
+9 7 6 2 1
+1 3 2 4 5
+8 6 4 4 1
+1 3 6 7 9
+
+";
+
+        let reports = Day2::parse(input).expect("Invalid input");
+
+        assert_eq!(reports.part1(), 2);
+        assert_eq!(reports.part2(), 4);
+    }
+}
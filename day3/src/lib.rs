@@ -0,0 +1,70 @@
+use regex::Regex;
+use solution::{ParseError, Solution};
+
+pub struct Day3 {
+    input: String,
+    re: Regex,
+}
+
+impl Solution for Day3 {
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let re = Regex::new(r"mul\((\d+),(\d+)\)|do\(\)|don't\(\)")?;
+        Ok(Self {
+            input: input.to_owned(),
+            re,
+        })
+    }
+
+    /// Sum of all multiplications
+    fn part1(&self) -> i64 {
+        let mut result: i64 = 0;
+        for matched in self.re.captures_iter(&self.input) {
+            if let (Some(num1), Some(num2)) = (matched.get(1), matched.get(2)) {
+                let num1 = num1.as_str().parse::<i64>().unwrap();
+                let num2 = num2.as_str().parse::<i64>().unwrap();
+                result += num1 * num2;
+            }
+        }
+        result
+    }
+
+    /// Sum of all multiplications with enable
+    fn part2(&self) -> i64 {
+        let mut result: i64 = 0;
+        let mut enabled = true;
+        for matched in self.re.captures_iter(&self.input) {
+            if let (Some(num1), Some(num2)) = (matched.get(1), matched.get(2)) {
+                if enabled {
+                    let num1 = num1.as_str().parse::<i64>().unwrap();
+                    let num2 = num2.as_str().parse::<i64>().unwrap();
+                    result += num1 * num2;
+                }
+            } else if matched.get(0).map_or(false, |m| m.as_str() == "do()") {
+                enabled = true;
+            } else if matched.get(0).map_or(false, |m| m.as_str() == "don't()") {
+                enabled = false;
+            }
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_multiplications() {
+        let input: &str = "xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))";
+        let memory = Day3::parse(input).expect("Invalid input");
+        assert_eq!(memory.part1(), 161);
+
+        let input: &str =
+            "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))";
+        let memory = Day3::parse(input).expect("Invalid input");
+        assert_eq!(memory.part2(), 48);
+    }
+}
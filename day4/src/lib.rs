@@ -0,0 +1,105 @@
+use grid::{Dir8, Grid, Point};
+use solution::{ParseError, Solution};
+
+enum Task {
+    First,
+    Second,
+}
+const WINNING_SEQUENCE: [char; 4] = ['X', 'M', 'A', 'S'];
+
+pub type Day4 = WordSearch;
+
+pub struct WordSearch {
+    puzzle: Grid<char>,
+}
+
+impl WordSearch {
+    fn run(&self, task: Task) -> u64 {
+        self.puzzle
+            .points()
+            .map(|pos| match task {
+                Task::First => self.search_dirs_task1(pos),
+                Task::Second => self.search_dirs_task2(pos),
+            })
+            .sum()
+    }
+
+    fn search_dirs_task1(&self, pos: Point) -> u64 {
+        Dir8::ALL
+            .into_iter()
+            .filter(|dir| {
+                self.puzzle
+                    .line(pos, dir.step())
+                    .map(|(_, &letter)| letter)
+                    .take(WINNING_SEQUENCE.len())
+                    .eq(WINNING_SEQUENCE)
+            })
+            .count() as u64
+    }
+
+    fn is_letter_at_position(&self, pos: Point, letter: char) -> bool {
+        self.puzzle.get(pos) == Some(&letter)
+    }
+
+    /// The diagonal through `pos` in the direction reads `MAS` one way or the other
+    fn is_mas(&self, pos: Point, dir: Dir8) -> bool {
+        let (front, back) = (pos + dir.step(), pos + dir.opposite().step());
+        self.is_letter_at_position(front, 'M') && self.is_letter_at_position(back, 'S')
+            || self.is_letter_at_position(front, 'S') && self.is_letter_at_position(back, 'M')
+    }
+
+    fn search_dirs_task2(&self, pos: Point) -> u64 {
+        if self.is_letter_at_position(pos, 'A')
+            && self.is_mas(pos, Dir8::SE)
+            && self.is_mas(pos, Dir8::NE)
+        {
+            return 1;
+        }
+        0
+    }
+}
+
+impl Solution for WordSearch {
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Self {
+            puzzle: Grid::parse(input, |letter| letter)?,
+        })
+    }
+
+    /// Number of words found in the puzzle 1
+    fn part1(&self) -> u64 {
+        self.run(Task::First)
+    }
+
+    /// Number of words found in the puzzle 2
+    fn part2(&self) -> u64 {
+        self.run(Task::Second)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_word_search() {
+        let input: &str = "MMMSXXMASM
+MSAMXMSMSA
+AMXSXMAAMM
+MSAMASMSMX
+XMASAMXAMM
+XXAMMXXAMA
+SMSMSASXSS
+SAXAMASAAA
+MAMMMXMMMM
+MXMXAXMASX";
+
+        let puzzle = Day4::parse(input).expect("Invalid input");
+
+        assert_eq!(puzzle.part1(), 18);
+        assert_eq!(puzzle.part2(), 9);
+    }
+}
@@ -0,0 +1,137 @@
+use solution::{ParseError, Solution};
+use std::collections::{HashMap, HashSet};
+
+pub struct Day5 {
+    /// The pages that must come after each page
+    rules: HashMap<usize, Vec<usize>>,
+    updates: Vec<Vec<usize>>,
+}
+
+impl Day5 {
+    /// Puts the pages of the update in the order of the rules, returns `true` if they already were
+    fn order(&self, nums: &mut [usize]) -> bool {
+        let mut is_correct = true;
+        let mut idx = 0;
+        let mut present = HashSet::new();
+
+        'outer: while idx < nums.len() {
+            present.insert(nums[idx]);
+            for &successor in self.rules.get(&nums[idx]).into_iter().flatten() {
+                if present.contains(&successor) {
+                    let successor_idx = nums.iter().position(|&x| x == successor).unwrap();
+                    nums[successor_idx] = nums[idx];
+                    nums[idx] = successor;
+                    present.clear();
+                    idx = 0;
+                    is_correct = false;
+                    continue 'outer;
+                }
+            }
+            idx += 1;
+        }
+        is_correct
+    }
+
+    /// Sum of the middle numbers of the updates that were correct, or of the corrected ones
+    fn middle_numbers_sum(&self, corrected: bool) -> usize {
+        self.updates
+            .iter()
+            .filter_map(|update| {
+                let mut nums = update.clone();
+                (self.order(&mut nums) != corrected).then(|| nums[nums.len() / 2])
+            })
+            .sum()
+    }
+}
+
+impl Solution for Day5 {
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        // the rules and the updates are separated by a blank line
+        let mut lines = input.lines().map(str::trim);
+        let mut rules: HashMap<usize, Vec<usize>> = HashMap::new();
+
+        for input in lines.by_ref().take_while(|line| !line.is_empty()) {
+            let (before, after) = input
+                .split_once('|')
+                .ok_or_else(|| ParseError::new(format!("Invalid rule '{input}'")))?;
+            rules
+                .entry(before.trim().parse()?)
+                .or_default()
+                .push(after.trim().parse()?);
+        }
+
+        let mut updates = vec![];
+        for input in lines.filter(|line| !line.is_empty()) {
+            let nums: Vec<usize> = input
+                .split(',')
+                .map(|s| s.trim().parse())
+                .collect::<Result<_, _>>()?;
+            updates.push(nums);
+        }
+        Ok(Self { rules, updates })
+    }
+
+    /// Middle numbers sum
+    fn part1(&self) -> usize {
+        self.middle_numbers_sum(false)
+    }
+
+    /// Middle corrected numbers sum
+    fn part2(&self) -> usize {
+        self.middle_numbers_sum(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "47|53
+97|13
+97|61
+97|47
+75|29
+61|13
+75|53
+29|13
+97|29
+53|29
+61|53
+97|53
+61|29
+47|13
+75|47
+97|75
+47|61
+75|61
+47|29
+75|13
+53|13
+
+75,47,61,53,29
+97,61,53,29,13
+75,29,13
+75,97,47,61,53
+61,13,29
+97,13,75,29,47
+";
+
+    #[test]
+    fn test_example() {
+        let manual = Day5::parse(INPUT).expect("Invalid input");
+
+        assert_eq!(manual.part1(), 143);
+        assert_eq!(manual.part2(), 123);
+    }
+
+    #[test]
+    fn test_example_crlf() {
+        let manual = Day5::parse(&INPUT.replace('\n', "\r\n")).expect("Invalid input");
+
+        assert_eq!(manual.part1(), 143);
+        assert_eq!(manual.part2(), 123);
+    }
+}
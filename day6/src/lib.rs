@@ -1,8 +1,16 @@
+pub use grid::geometry::{Dir4, Point};
+use grid::Grid;
+use solution::{ParseError, Solution};
+use std::collections::HashSet;
 use std::fmt;
-use std::ops::Add;
 
 #[derive(PartialEq, Clone)]
-pub enum MapObject {Guard, Obstacle, Empty, Visited}
+pub enum MapObject {
+    Guard,
+    Obstacle,
+    Empty,
+    Visited,
+}
 
 impl fmt::Display for MapObject {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -16,51 +24,166 @@ impl fmt::Display for MapObject {
     }
 }
 
-#[derive(Clone, Copy)]
-#[derive(PartialEq, Eq, Hash)]
-pub enum Dir {Left, Right, Down, Up}
-
-impl Dir {
-    fn rotate(&self) -> Self {
-        match self {
-            Dir::Left => Dir::Up,
-            Dir::Right => Dir::Down,
-            Dir::Up => Dir::Right,
-            Dir::Down => Dir::Left,
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Guard {
+    pub pos: Point,
+    pub dir: Dir4,
+}
+
+impl Guard {
+    pub fn rotate(&mut self) {
+        self.dir = self.dir.cw();
+    }
+}
+
+pub type Day6 = Board;
+
+#[derive(Clone)]
+pub struct Board {
+    guard: Guard,
+    guard_backup: Guard,
+    map: Grid<MapObject>,
+    map_backup: Grid<MapObject>,
+    count: u32,
+    path: HashSet<Point>,
+}
+
+impl Board {
+    fn new(map: Grid<MapObject>) -> Result<Self, ParseError> {
+        let start = map
+            .find(|object| *object == MapObject::Guard)
+            .ok_or(ParseError::new("No guard on the map"))?;
+        let guard = Guard {
+            pos: start,
+            dir: Dir4::Up,
+        };
+        Ok(Self {
+            guard,
+            guard_backup: guard,
+            map_backup: map.clone(),
+            map,
+            count: 1,
+            path: HashSet::new(),
+        })
+    }
+
+    fn restore_map(&mut self) {
+        self.guard = self.guard_backup.clone();
+        self.map = self.map_backup.clone();
+        self.count = 0;
+    }
+
+    fn try_move(&mut self, pos: Point) -> bool {
+        if self
+            .map
+            .get(pos)
+            .is_some_and(|object| *object != MapObject::Obstacle)
+        {
+            if self.map[self.guard.pos] != MapObject::Visited {
+                self.count += 1;
+            }
+            self.map[self.guard.pos] = MapObject::Visited;
+            self.guard.pos = pos;
+            self.path.insert(self.guard.pos);
+            return true;
         }
+        false
     }
 
-    pub fn get_change(&self) -> Pos {
-         match self {
-             Dir::Left => Pos(0, -1),
-             Dir::Right => Pos(0, 1),
-             Dir::Up => Pos(-1, 0),
-             Dir::Down => Pos(1, 0),
-         }
+    fn move_guard(&mut self) -> bool {
+        let mut count = 0;
+        while count < 4 {
+            let new_pos = self.guard.pos + self.guard.dir.step();
+            if !self.map.is_in_bounds(new_pos) {
+                return false;
+            }
+            if self.try_move(new_pos) {
+                return true;
+            }
+            self.guard.rotate();
+            count += 1;
+        }
+        false
+    }
+
+    /// The board after the guard left the map
+    fn walked(&self) -> Board {
+        let mut board = self.clone();
+        while board.move_guard() {}
+        board
     }
 }
 
-#[derive(Clone, Copy)]
-#[derive(PartialEq, Eq, Hash)]
-pub struct Pos(pub isize, pub isize);
+impl fmt::Display for Board {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.map)
+    }
+}
 
-impl Add<Pos> for &Pos {
-    type Output = Pos;
+impl Solution for Board {
+    type Answer1 = u32;
+    type Answer2 = usize;
 
-    fn add(self, other: Pos) -> Pos {
-        Pos(self.0 + other.0, self.1 + other.1)
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let map = Grid::parse(input, |part| match part {
+            '#' => MapObject::Obstacle,
+            '^' => MapObject::Guard,
+            _ => MapObject::Empty,
+        })?;
+        Board::new(map)
     }
-}
 
-#[derive(Clone, Copy)]
-#[derive(PartialEq, Eq, Hash)]
-pub struct Guard {
-    pub pos: Pos,
-    pub dir: Dir,
+    /// Guard move count
+    fn part1(&self) -> u32 {
+        self.walked().count
+    }
+
+    /// Guard stuck in a loop count
+    fn part2(&self) -> usize {
+        let mut board = self.walked();
+        let original_path = board.path.clone();
+
+        let mut loop_count = 0;
+        for pos in original_path {
+            if pos == board.guard_backup.pos {
+                continue;
+            }
+
+            board.restore_map();
+            let mut is_loop = HashSet::new();
+
+            board.map[pos] = MapObject::Obstacle;
+            while board.move_guard() {
+                if !is_loop.insert(board.guard.clone()) {
+                    loop_count += 1;
+                    break;
+                }
+            }
+        }
+        loop_count
+    }
 }
 
-impl Guard {
-    pub fn rotate(&mut self){
-        self.dir = self.dir.rotate();
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_guard_walk() {
+        let input: &str = "....#.....
+.........#
+..........
+..#.......
+.......#..
+..........
+.#..^.....
+........#.
+#.........
+......#...";
+
+        let board = Day6::parse(input).expect("Invalid input");
+
+        assert_eq!(board.part1(), 41);
+        assert_eq!(board.part2(), 6);
     }
 }
@@ -0,0 +1,127 @@
+use solution::{ParseError, Solution};
+use std::collections::{HashSet, VecDeque};
+
+#[derive(Clone, Copy)]
+enum Operands {
+    Add,
+    Mul,
+    Concat,
+}
+
+#[derive(Debug, Clone)]
+struct Operation {
+    idx: usize,
+    inter_res: i64,
+    vals: VecDeque<i64>,
+}
+
+pub struct Day7 {
+    op_results: Vec<i64>,
+    operations: Vec<Operation>,
+}
+
+impl Day7 {
+    /// Sum of the results of the equations that can be made true with the operands
+    fn calibration(&self, operands: &[Operands]) -> i64 {
+        let mut queue: VecDeque<Operation> = self.operations.iter().cloned().collect();
+        let mut seen: HashSet<usize> = HashSet::new();
+
+        let mut result = 0;
+        while !queue.is_empty() {
+            let mut op = queue.pop_front().unwrap();
+            if seen.contains(&op.idx) {
+                continue;
+            }
+            if let Some(num) = op.vals.pop_front() {
+                for operand in operands {
+                    let mut new_op = op.clone();
+                    match operand {
+                        Operands::Add => new_op.inter_res += num,
+                        Operands::Mul => new_op.inter_res *= num,
+                        Operands::Concat => {
+                            new_op.inter_res = new_op.inter_res * 10_i64.pow(num.ilog10() + 1) + num
+                        }
+                    }
+                    if new_op.inter_res == self.op_results[new_op.idx]
+                        && new_op.vals.is_empty()
+                        && !seen.contains(&new_op.idx)
+                    {
+                        result += new_op.inter_res;
+                        seen.insert(new_op.idx);
+                        break;
+                    } else if new_op.inter_res <= self.op_results[new_op.idx] {
+                        queue.push_back(new_op);
+                    }
+                }
+            }
+        }
+        result
+    }
+}
+
+impl Solution for Day7 {
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let mut operations = vec![];
+        let mut op_results: Vec<i64> = vec![];
+        for (idx, input) in input
+            .lines()
+            .take_while(|line| !line.is_empty())
+            .enumerate()
+        {
+            let (result, vals) = input
+                .split_once(':')
+                .ok_or(ParseError::new("Missing ':'"))?;
+            op_results.push(result.parse()?);
+            let mut vals: VecDeque<i64> = vals
+                .split_whitespace()
+                .map(|x| x.parse())
+                .collect::<Result<_, _>>()?;
+            let inter_res = vals.pop_front().ok_or(ParseError::new("Missing numbers"))?;
+            operations.push(Operation {
+                idx,
+                inter_res,
+                vals,
+            });
+        }
+        Ok(Self {
+            op_results,
+            operations,
+        })
+    }
+
+    /// Total calibration result with addition and multiplication
+    fn part1(&self) -> i64 {
+        self.calibration(&[Operands::Add, Operands::Mul])
+    }
+
+    /// Total calibration result with concatenation too
+    fn part2(&self) -> i64 {
+        self.calibration(&[Operands::Add, Operands::Mul, Operands::Concat])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_calibration() {
+        let input: &str = "190: 10 19
+3267: 81 40 27
+83: 17 5
+156: 15 6
+7290: 6 8 6 15
+161011: 16 10 13
+192: 17 8 14
+21037: 9 7 18 13
+292: 11 6 16 20";
+
+        let equations = Day7::parse(input).expect("Invalid input");
+
+        assert_eq!(equations.part1(), 3749);
+        assert_eq!(equations.part2(), 11387);
+    }
+}
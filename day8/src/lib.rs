@@ -0,0 +1,225 @@
+use grid::{Grid, Point};
+use solution::{ParseError, Solution};
+use std::collections::{HashMap, HashSet};
+
+pub type Day8 = Map;
+
+#[derive(Clone)]
+pub struct Map {
+    grid: Grid<char>,
+    antennas: HashMap<char, Vec<Point>>,
+    max_dist: Option<usize>,
+}
+
+impl Map {
+    fn new(grid: Grid<char>) -> Self {
+        Self {
+            grid,
+            antennas: HashMap::new(),
+            max_dist: None,
+        }
+    }
+
+    fn find_antennas(&mut self) {
+        for (pos, &antenna) in self.grid.iter() {
+            if antenna != '.' {
+                self.antennas.entry(antenna).or_default().push(pos);
+            }
+        }
+    }
+
+    fn get_antinodes(&self, pos1: &Point, pos2: &Point, max_dist: Option<usize>) -> Vec<Point> {
+        if pos1 == pos2 {
+            return vec![];
+        }
+        let mut result: Vec<Point> = vec![];
+        let mut dist = 1;
+
+        if let Some(max) = max_dist {
+            if max > 1 {
+                result.push((*pos1).clone());
+                result.push((*pos2).clone());
+            }
+        } else {
+            result.push((*pos1).clone());
+            result.push((*pos2).clone());
+        }
+
+        loop {
+            let diff = *pos1 - *pos2;
+
+            let new_pos = [*pos1 + diff * dist, *pos2 - diff * dist];
+
+            let mut new_added = false;
+            for &pos in &new_pos {
+                if self.grid.is_in_bounds(pos) {
+                    result.push(pos.clone());
+                    new_added = true;
+                }
+            }
+            if !new_added {
+                break;
+            }
+
+            dist += 1;
+            if let Some(max_dist) = max_dist {
+                if dist > max_dist as isize {
+                    break;
+                }
+            }
+        }
+        result
+    }
+
+    fn count_antinodes(&mut self) -> usize {
+        self.find_antennas();
+        let mut result: HashSet<Point> = HashSet::new();
+
+        for &anntena_type in self.antennas.keys() {
+            let locations = self.antennas.get(&anntena_type).unwrap();
+            for i in 0..locations.len() {
+                for j in i..locations.len() {
+                    let locs = self.get_antinodes(&locations[i], &locations[j], self.max_dist);
+                    for loc in locs {
+                        result.insert(loc);
+                    }
+                }
+            }
+        }
+        result.iter().count()
+    }
+}
+
+impl Solution for Map {
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let input: String = input
+            .lines()
+            .take_while(|line| !line.is_empty())
+            .map(|line| format!("{line}\n"))
+            .collect();
+        Ok(Map::new(Grid::parse(&input, |c| c)?))
+    }
+
+    /// Locations with antinodes count
+    fn part1(&self) -> usize {
+        let mut map = self.clone();
+        map.max_dist = Some(1);
+        map.count_antinodes()
+    }
+
+    /// Locations with antinodes (including a resonance) count
+    fn part2(&self) -> usize {
+        let mut map = self.clone();
+        map.max_dist = None;
+        map.count_antinodes()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_getting_antinodes() {
+        let map = Map::new(Grid::new(10, 10, '.'));
+        assert_eq!(
+            map.get_antinodes(&Point::new(7, 7), &Point::new(8, 8), Some(1)),
+            vec![Point::new(6, 6), Point::new(9, 9)]
+        );
+        assert_eq!(
+            map.get_antinodes(&Point::new(8, 8), &Point::new(9, 9), Some(2)),
+            vec![
+                Point::new(8, 8),
+                Point::new(9, 9),
+                Point::new(7, 7),
+                Point::new(6, 6)
+            ]
+        );
+        assert_eq!(
+            map.get_antinodes(&Point::new(8, 8), &Point::new(8, 8), Some(1)),
+            vec![]
+        );
+        assert_eq!(
+            map.get_antinodes(&Point::new(1, 0), &Point::new(3, 0), Some(1)),
+            vec![Point::new(5, 0)]
+        );
+        assert_eq!(
+            map.get_antinodes(&Point::new(0, 0), &Point::new(2, 0), None),
+            vec![
+                Point::new(0, 0),
+                Point::new(2, 0),
+                Point::new(4, 0),
+                Point::new(6, 0),
+                Point::new(8, 0)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_antinode_count_dist_1() {
+        let mut map = Map::new(Grid::new(12, 12, '.'));
+        map.max_dist = Some(1);
+        let input = "............
+........0...
+.....0......
+.......0....
+....0.......
+......A.....
+............
+............
+........A...
+.........A..
+............
+............";
+
+        map.grid = Grid::parse(input, |c| c).unwrap();
+
+        assert_eq!(map.count_antinodes(), 14);
+    }
+
+    #[test]
+    fn test_antinode_count_unlimited() {
+        let mut map = Map::new(Grid::new(12, 12, '.'));
+        let input = "............
+........0...
+.....0......
+.......0....
+....0.......
+......A.....
+............
+............
+........A...
+.........A..
+............
+............";
+
+        map.grid = Grid::parse(input, |c| c).unwrap();
+
+        assert_eq!(map.count_antinodes(), 34);
+    }
+
+    #[test]
+    fn test_example() {
+        let input: &str = "............
+........0...
+.....0......
+.......0....
+....0.......
+......A.....
+............
+............
+........A...
+.........A..
+............
+............
+";
+
+        let map = Day8::parse(input).expect("Invalid input");
+
+        assert_eq!(map.part1(), 14);
+        assert_eq!(map.part2(), 34);
+    }
+}
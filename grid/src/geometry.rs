@@ -0,0 +1,307 @@
+//! Points and directions on a plane where `row` grows downwards and `col` to the right, as the
+//! maps of the puzzles are read.
+use std::fmt;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+use std::str::FromStr;
+
+/// Position in a grid. The coordinates are signed so that a point can step outside of the grid
+/// and be checked afterwards.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point {
+    pub row: isize,
+    pub col: isize,
+}
+
+impl Point {
+    pub const ORIGIN: Point = Point::new(0, 0);
+
+    pub const fn new(row: isize, col: isize) -> Self {
+        Self { row, col }
+    }
+
+    /// Number of 4-neighbour steps between the points
+    pub fn manhattan(self, other: Point) -> usize {
+        self.row.abs_diff(other.row) + self.col.abs_diff(other.col)
+    }
+
+    /// Number of 8-neighbour steps between the points
+    pub fn chebyshev(self, other: Point) -> usize {
+        self.row
+            .abs_diff(other.row)
+            .max(self.col.abs_diff(other.col))
+    }
+
+    /// The point moved into `0..size.row` x `0..size.col`, as on a torus where leaving one edge
+    /// enters the opposite one
+    pub fn wrap(self, size: Point) -> Point {
+        Point::new(self.row.rem_euclid(size.row), self.col.rem_euclid(size.col))
+    }
+
+    /// `self + other` on a torus of the given size
+    pub fn wrapping_add(self, other: Point, size: Point) -> Point {
+        (self + other).wrap(size)
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, other: Point) -> Point {
+        Point::new(self.row + other.row, self.col + other.col)
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, other: Point) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, other: Point) -> Point {
+        Point::new(self.row - other.row, self.col - other.col)
+    }
+}
+
+impl SubAssign for Point {
+    fn sub_assign(&mut self, other: Point) {
+        *self = *self - other;
+    }
+}
+
+impl Mul<isize> for Point {
+    type Output = Point;
+
+    fn mul(self, scalar: isize) -> Point {
+        Point::new(self.row * scalar, self.col * scalar)
+    }
+}
+
+impl Neg for Point {
+    type Output = Point;
+
+    fn neg(self) -> Point {
+        Point::new(-self.row, -self.col)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseDirError(String);
+
+impl fmt::Display for ParseDirError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "'{}' is not a direction", self.0)
+    }
+}
+
+impl std::error::Error for ParseDirError {}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Dir4 {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Dir4 {
+    /// Clockwise from up
+    pub const ALL: [Dir4; 4] = [Dir4::Up, Dir4::Right, Dir4::Down, Dir4::Left];
+
+    pub const fn step(self) -> Point {
+        match self {
+            Dir4::Up => Point::new(-1, 0),
+            Dir4::Right => Point::new(0, 1),
+            Dir4::Down => Point::new(1, 0),
+            Dir4::Left => Point::new(0, -1),
+        }
+    }
+
+    /// Turned a quarter clockwise
+    pub fn cw(self) -> Self {
+        Self::ALL[(self as usize + 1) % 4]
+    }
+
+    /// Turned a quarter counter-clockwise
+    pub fn ccw(self) -> Self {
+        Self::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn opposite(self) -> Self {
+        Self::ALL[(self as usize + 2) % 4]
+    }
+
+    pub fn is_vertical(self) -> bool {
+        matches!(self, Dir4::Up | Dir4::Down)
+    }
+}
+
+impl From<Dir4> for Point {
+    fn from(dir: Dir4) -> Point {
+        dir.step()
+    }
+}
+
+/// `^`, `>`, `v`, `<` or `N`, `E`, `S`, `W`
+impl TryFrom<char> for Dir4 {
+    type Error = ParseDirError;
+
+    fn try_from(c: char) -> Result<Self, ParseDirError> {
+        match c {
+            '^' | 'N' => Ok(Dir4::Up),
+            '>' | 'E' => Ok(Dir4::Right),
+            'v' | 'S' => Ok(Dir4::Down),
+            '<' | 'W' => Ok(Dir4::Left),
+            _ => Err(ParseDirError(c.to_string())),
+        }
+    }
+}
+
+impl FromStr for Dir4 {
+    type Err = ParseDirError;
+
+    fn from_str(s: &str) -> Result<Self, ParseDirError> {
+        let mut chars = s.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => Dir4::try_from(c),
+            _ => Err(ParseDirError(s.to_string())),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Dir8 {
+    N,
+    NE,
+    E,
+    SE,
+    S,
+    SW,
+    W,
+    NW,
+}
+
+impl Dir8 {
+    /// Clockwise from north
+    pub const ALL: [Dir8; 8] = [
+        Dir8::N,
+        Dir8::NE,
+        Dir8::E,
+        Dir8::SE,
+        Dir8::S,
+        Dir8::SW,
+        Dir8::W,
+        Dir8::NW,
+    ];
+
+    pub const fn step(self) -> Point {
+        match self {
+            Dir8::N => Point::new(-1, 0),
+            Dir8::NE => Point::new(-1, 1),
+            Dir8::E => Point::new(0, 1),
+            Dir8::SE => Point::new(1, 1),
+            Dir8::S => Point::new(1, 0),
+            Dir8::SW => Point::new(1, -1),
+            Dir8::W => Point::new(0, -1),
+            Dir8::NW => Point::new(-1, -1),
+        }
+    }
+
+    /// Turned an eighth clockwise
+    pub fn cw(self) -> Self {
+        Self::ALL[(self as usize + 1) % 8]
+    }
+
+    /// Turned an eighth counter-clockwise
+    pub fn ccw(self) -> Self {
+        Self::ALL[(self as usize + 7) % 8]
+    }
+
+    pub fn opposite(self) -> Self {
+        Self::ALL[(self as usize + 4) % 8]
+    }
+
+    pub fn is_diagonal(self) -> bool {
+        self as usize % 2 == 1
+    }
+}
+
+impl From<Dir4> for Dir8 {
+    fn from(dir: Dir4) -> Dir8 {
+        Dir8::ALL[dir as usize * 2]
+    }
+}
+
+impl From<Dir8> for Point {
+    fn from(dir: Dir8) -> Point {
+        dir.step()
+    }
+}
+
+/// `N`, `NE`, `E`, `SE`, `S`, `SW`, `W`, `NW`, or one of the arrows of `Dir4`
+impl FromStr for Dir8 {
+    type Err = ParseDirError;
+
+    fn from_str(s: &str) -> Result<Self, ParseDirError> {
+        match s {
+            "NE" => Ok(Dir8::NE),
+            "SE" => Ok(Dir8::SE),
+            "SW" => Ok(Dir8::SW),
+            "NW" => Ok(Dir8::NW),
+            _ => s.parse::<Dir4>().map(Dir8::from),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_point_arithmetic() {
+        let a = Point::new(1, 2);
+        let b = Point::new(4, -2);
+        assert_eq!(a + b, Point::new(5, 0));
+        assert_eq!(a - b, Point::new(-3, 4));
+        assert_eq!(a * 3, Point::new(3, 6));
+        assert_eq!(-a, Point::new(-1, -2));
+        assert_eq!(a.manhattan(b), 7);
+        assert_eq!(a.chebyshev(b), 4);
+    }
+
+    #[test]
+    fn test_wrap() {
+        let size = Point::new(7, 11);
+        assert_eq!(Point::new(-1, 11).wrap(size), Point::new(6, 0));
+        assert_eq!(
+            Point::new(4, 2).wrapping_add(Point::new(-3, 2) * 5, size),
+            Point::new(3, 1)
+        );
+    }
+
+    #[test]
+    fn test_turns() {
+        assert_eq!(Dir4::Left.cw(), Dir4::Up);
+        assert_eq!(Dir4::Up.ccw(), Dir4::Left);
+        assert_eq!(Dir4::Right.opposite(), Dir4::Left);
+        assert_eq!(Dir8::NW.cw(), Dir8::N);
+        assert_eq!(Dir8::N.ccw(), Dir8::NW);
+        assert_eq!(Dir8::SE.opposite(), Dir8::NW);
+        assert_eq!(Dir8::from(Dir4::Down), Dir8::S);
+        for dir in Dir8::ALL {
+            assert_eq!(dir.step() + dir.opposite().step(), Point::ORIGIN);
+        }
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(Dir4::try_from('v'), Ok(Dir4::Down));
+        assert_eq!("W".parse(), Ok(Dir4::Left));
+        assert!("x".parse::<Dir4>().is_err());
+        assert_eq!("SW".parse(), Ok(Dir8::SW));
+        assert_eq!("^".parse(), Ok(Dir8::N));
+        assert!("NN".parse::<Dir8>().is_err());
+    }
+}
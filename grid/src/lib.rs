@@ -0,0 +1,322 @@
+//! A rectangular grid of cells stored row by row, as most of the puzzles give their maps.
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+pub mod geometry;
+
+pub use geometry::{Dir4, Dir8, Point};
+
+/// Steps to the 4 neighbours, clockwise from up
+pub const NEIGHBOURS_4: [Point; 4] = [
+    Dir4::Up.step(),
+    Dir4::Right.step(),
+    Dir4::Down.step(),
+    Dir4::Left.step(),
+];
+
+/// Steps to the 8 neighbours, clockwise from up
+pub const NEIGHBOURS_8: [Point; 8] = [
+    Dir8::N.step(),
+    Dir8::NE.step(),
+    Dir8::E.step(),
+    Dir8::SE.step(),
+    Dir8::S.step(),
+    Dir8::SW.step(),
+    Dir8::W.step(),
+    Dir8::NW.step(),
+];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseGridError {
+    Empty,
+    /// A line is not as long as the first one
+    UnevenLine {
+        line: usize,
+        len: usize,
+        expected: usize,
+    },
+}
+
+impl fmt::Display for ParseGridError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseGridError::Empty => write!(f, "the grid is empty"),
+            ParseGridError::UnevenLine {
+                line,
+                len,
+                expected,
+            } => write!(
+                f,
+                "line {line} has {len} cells instead of {expected} like the first one"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ParseGridError {}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    rows: usize,
+    cols: usize,
+}
+
+impl<T> Grid<T> {
+    /// Grid of `rows` x `cols` cells, all set to `value`
+    pub fn new(rows: usize, cols: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            cells: vec![value; rows * cols],
+            rows,
+            cols,
+        }
+    }
+
+    /// Grid with a cell for each char of the text, the lines are the rows. Blank lines around the
+    /// grid are ignored.
+    pub fn parse(text: &str, mut f: impl FnMut(char) -> T) -> Result<Self, ParseGridError> {
+        let mut cells = vec![];
+        let mut rows = 0;
+        let mut cols = 0;
+        for (line_idx, line) in text.trim_matches('\n').lines().enumerate() {
+            let line = line.trim_end_matches('\r');
+            let len = line.chars().count();
+            if rows == 0 {
+                cols = len;
+            } else if len != cols {
+                return Err(ParseGridError::UnevenLine {
+                    line: line_idx + 1,
+                    len,
+                    expected: cols,
+                });
+            }
+            cells.extend(line.chars().map(&mut f));
+            rows += 1;
+        }
+        if cells.is_empty() {
+            return Err(ParseGridError::Empty);
+        }
+        Ok(Self { cells, rows, cols })
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    pub fn is_in_bounds(&self, pos: Point) -> bool {
+        pos.row >= 0
+            && pos.col >= 0
+            && (pos.row as usize) < self.rows
+            && (pos.col as usize) < self.cols
+    }
+
+    fn offset(&self, pos: Point) -> Option<usize> {
+        self.is_in_bounds(pos)
+            .then(|| pos.row as usize * self.cols + pos.col as usize)
+    }
+
+    fn point(&self, offset: usize) -> Point {
+        Point::new((offset / self.cols) as isize, (offset % self.cols) as isize)
+    }
+
+    pub fn get(&self, pos: Point) -> Option<&T> {
+        self.offset(pos).map(|offset| &self.cells[offset])
+    }
+
+    pub fn get_mut(&mut self, pos: Point) -> Option<&mut T> {
+        self.offset(pos).map(|offset| &mut self.cells[offset])
+    }
+
+    /// `pos + step` if it is in the grid
+    pub fn step(&self, pos: Point, step: Point) -> Option<Point> {
+        Some(pos + step).filter(|&pos| self.is_in_bounds(pos))
+    }
+
+    /// All the points, row by row
+    pub fn points(&self) -> impl Iterator<Item = Point> + '_ {
+        (0..self.cells.len()).map(|offset| self.point(offset))
+    }
+
+    /// All the cells with their point, row by row
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.cells
+            .iter()
+            .enumerate()
+            .map(|(offset, cell)| (self.point(offset), cell))
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        self.cells.iter_mut()
+    }
+
+    /// Points of the cells matching the predicate, row by row
+    pub fn positions<'a>(
+        &'a self,
+        mut predicate: impl FnMut(&T) -> bool + 'a,
+    ) -> impl Iterator<Item = Point> + 'a {
+        self.iter()
+            .filter(move |(_, cell)| predicate(cell))
+            .map(|(pos, _)| pos)
+    }
+
+    /// The first point of a cell matching the predicate, row by row
+    pub fn find(&self, predicate: impl FnMut(&T) -> bool) -> Option<Point> {
+        self.positions(predicate).next()
+    }
+
+    /// The up, right, down and left neighbours that are in the grid
+    pub fn neighbours4(&self, pos: Point) -> impl Iterator<Item = Point> + '_ {
+        NEIGHBOURS_4
+            .into_iter()
+            .filter_map(move |step| self.step(pos, step))
+    }
+
+    /// The neighbours in the grid, diagonals included
+    pub fn neighbours8(&self, pos: Point) -> impl Iterator<Item = Point> + '_ {
+        NEIGHBOURS_8
+            .into_iter()
+            .filter_map(move |step| self.step(pos, step))
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.cols..(row + 1) * self.cols]
+    }
+
+    pub fn col(&self, col: usize) -> impl Iterator<Item = &T> {
+        // a grid without columns has no cells, `step_by` only needs a non-zero step
+        self.cells[col..].iter().step_by(self.cols.max(1))
+    }
+
+    /// The cells from `start` going by `step` until the edge of the grid, `start` included
+    pub fn line(&self, start: Point, step: Point) -> impl Iterator<Item = (Point, &T)> {
+        std::iter::successors(Some(start), move |&pos| Some(pos + step))
+            .map_while(|pos| self.get(pos).map(|cell| (pos, cell)))
+    }
+
+    /// The cells from `start` going down and right
+    pub fn diagonal(&self, start: Point) -> impl Iterator<Item = (Point, &T)> {
+        self.line(start, Dir8::SE.step())
+    }
+
+    /// The cells from `start` going down and left
+    pub fn anti_diagonal(&self, start: Point) -> impl Iterator<Item = (Point, &T)> {
+        self.line(start, Dir8::SW.step())
+    }
+
+    /// Grid of the same size with the cells converted
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            cells: self.cells.iter().map(f).collect(),
+            rows: self.rows,
+            cols: self.cols,
+        }
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Point) -> &T {
+        self.get(pos)
+            .unwrap_or_else(|| panic!("{pos:?} is outside of the grid"))
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, pos: Point) -> &mut T {
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("{pos:?} is outside of the grid"))
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in 0..self.rows {
+            for cell in self.row(row) {
+                write!(f, "{cell}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEXT: &str = "abc\ndef\n";
+
+    #[test]
+    fn test_parse_and_display() {
+        let grid = Grid::parse(TEXT, |c| c).unwrap();
+        assert_eq!((grid.rows(), grid.cols()), (2, 3));
+        assert_eq!(grid[Point::new(1, 2)], 'f');
+        assert_eq!(grid.to_string(), TEXT);
+
+        assert_eq!(
+            Grid::parse("abc\nde", |c| c),
+            Err(ParseGridError::UnevenLine {
+                line: 2,
+                len: 2,
+                expected: 3
+            })
+        );
+        assert_eq!(Grid::parse("\n", |c| c), Err(ParseGridError::Empty));
+    }
+
+    #[test]
+    fn test_empty_grid() {
+        let grid = Grid::new(2, 0, 'x');
+        assert_eq!(grid.col(0).count(), 0);
+        assert_eq!(grid.points().count(), 0);
+        assert_eq!(grid.to_string(), "\n\n");
+        assert_eq!(Grid::new(0, 0, 'x').to_string(), "");
+        assert_eq!(Grid::parse("", |c| c), Err(ParseGridError::Empty));
+    }
+
+    #[test]
+    fn test_checked_indexing() {
+        let mut grid = Grid::new(2, 3, 0);
+        assert_eq!(grid.get(Point::new(-1, 0)), None);
+        assert_eq!(grid.get(Point::new(0, 3)), None);
+        grid[Point::new(1, 1)] = 5;
+        assert_eq!(grid.get(Point::new(1, 1)), Some(&5));
+        assert_eq!(grid.find(|&cell| cell == 5), Some(Point::new(1, 1)));
+        assert_eq!(grid.step(Point::new(1, 1), Point::new(1, 0)), None);
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = Grid::new(3, 3, ());
+        assert_eq!(
+            grid.neighbours4(Point::new(0, 0)).collect::<Vec<_>>(),
+            [Point::new(0, 1), Point::new(1, 0)]
+        );
+        assert_eq!(grid.neighbours4(Point::new(1, 1)).count(), 4);
+        assert_eq!(grid.neighbours8(Point::new(1, 1)).count(), 8);
+        assert_eq!(grid.neighbours8(Point::new(2, 0)).count(), 3);
+    }
+
+    #[test]
+    fn test_views() {
+        let grid = Grid::parse(TEXT, |c| c).unwrap();
+        assert_eq!(grid.row(1), ['d', 'e', 'f']);
+        assert_eq!(grid.col(2).collect::<String>(), "cf");
+        let cells =
+            |line: Vec<(Point, &char)>| line.into_iter().map(|(_, &c)| c).collect::<String>();
+        assert_eq!(cells(grid.diagonal(Point::new(0, 0)).collect()), "ae");
+        assert_eq!(cells(grid.anti_diagonal(Point::new(0, 2)).collect()), "ce");
+        assert_eq!(
+            cells(grid.line(Point::new(1, 2), Point::new(0, -1)).collect()),
+            "fed"
+        );
+    }
+}
@@ -0,0 +1,45 @@
+//! Where the day packages and their inputs are, shared by `start_coding` and the `aoc` runner so
+//! that the runner doesn't need the downloader to read the inputs.
+use std::path::{Path, PathBuf};
+
+pub mod store;
+
+pub const MANIFEST_FILE_NAME: &str = "Cargo.toml";
+
+/// The package of the day in `YEAR/dayN`, or in `dayN` of the root as before the year
+/// directories if the year is `legacy_year`, the year of those packages
+pub fn find_package(root: &Path, year: i32, day: u32, legacy_year: Option<i32>) -> Option<PathBuf> {
+    let name = format!("day{day}");
+    let legacy = (legacy_year == Some(year)).then(|| root.join(&name));
+    [Some(root.join(year.to_string()).join(&name)), legacy]
+        .into_iter()
+        .flatten()
+        .find(|dir| dir.join(MANIFEST_FILE_NAME).is_file())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn test_find_package() {
+        let root =
+            std::env::temp_dir().join(format!("puzzle_input_package_{}", std::process::id()));
+        let package = |dir: PathBuf| {
+            fs::create_dir_all(&dir).unwrap();
+            fs::write(dir.join(MANIFEST_FILE_NAME), "").unwrap();
+            dir
+        };
+        let legacy = package(root.join("day1"));
+        let day2 = package(root.join("2024").join("day2"));
+
+        // the days in the root belong to the legacy year only
+        assert_eq!(find_package(&root, 2024, 1, Some(2024)), Some(legacy));
+        assert_eq!(find_package(&root, 2023, 1, Some(2024)), None);
+        assert_eq!(find_package(&root, 2024, 1, None), None);
+        assert_eq!(find_package(&root, 2024, 2, None), Some(day2));
+
+        fs::remove_dir_all(root).unwrap();
+    }
+}
@@ -0,0 +1,145 @@
+//! Inputs encrypted at rest, so that they can be committed to a shared repository. The input
+//! `input.txt` is stored as `input.txt.enc`: a magic header, a random salt and nonce, and the
+//! input encrypted with AES-256-GCM. The key is derived with PBKDF2-HMAC-SHA256 from a
+//! passphrase, or from the content of a key file.
+use aes_gcm::aead::rand_core::RngCore;
+use aes_gcm::aead::{Aead, AeadCore, KeyInit, OsRng};
+use aes_gcm::{Aes256Gcm, Key, Nonce};
+use sha2::Sha256;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+pub const ENCRYPTED_EXTENSION: &str = "enc";
+pub const PASSPHRASE_ENV_VAR: &str = "AOC_INPUT_PASSPHRASE";
+
+const MAGIC: &[u8] = b"SCINPUT1";
+const SALT_LENGTH: usize = 16;
+const NONCE_LENGTH: usize = 12;
+const ROUNDS: u32 = 100_000;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Secret {
+    Passphrase(String),
+    KeyFile(PathBuf),
+}
+
+impl Secret {
+    /// The key file if there is one, otherwise the passphrase in `AOC_INPUT_PASSPHRASE`
+    pub fn find(key_file: Option<&Path>) -> Option<Self> {
+        match key_file {
+            Some(path) => Some(Secret::KeyFile(path.to_path_buf())),
+            None => std::env::var(PASSPHRASE_ENV_VAR)
+                .ok()
+                .filter(|passphrase| !passphrase.is_empty())
+                .map(Secret::Passphrase),
+        }
+    }
+
+    fn bytes(&self) -> io::Result<Vec<u8>> {
+        match self {
+            Secret::Passphrase(passphrase) => Ok(passphrase.as_bytes().to_vec()),
+            Secret::KeyFile(path) => {
+                let content = fs::read(path)?;
+                Ok(content.trim_ascii_end().to_vec())
+            }
+        }
+    }
+
+    fn key(&self, salt: &[u8]) -> io::Result<Key<Aes256Gcm>> {
+        let mut key = Key::<Aes256Gcm>::default();
+        pbkdf2::pbkdf2_hmac::<Sha256>(&self.bytes()?, salt, ROUNDS, &mut key);
+        Ok(key)
+    }
+}
+
+fn invalid_data(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+/// `input.txt.enc` for `input.txt`
+pub fn encrypted_path(path: &Path) -> PathBuf {
+    let mut name = path.file_name().unwrap_or_default().to_owned();
+    name.push(format!(".{ENCRYPTED_EXTENSION}"));
+    path.with_file_name(name)
+}
+
+pub fn encrypt(plaintext: &[u8], secret: &Secret) -> io::Result<Vec<u8>> {
+    let mut salt = [0; SALT_LENGTH];
+    OsRng.fill_bytes(&mut salt);
+    let nonce = Aes256Gcm::generate_nonce(&mut OsRng);
+    let ciphertext = Aes256Gcm::new(&secret.key(&salt)?)
+        .encrypt(&nonce, plaintext)
+        .map_err(|_| invalid_data("the input could not be encrypted"))?;
+
+    Ok([MAGIC, &salt, &nonce, &ciphertext].concat())
+}
+
+pub fn decrypt(data: &[u8], secret: &Secret) -> io::Result<Vec<u8>> {
+    let data = data
+        .strip_prefix(MAGIC)
+        .filter(|data| data.len() >= SALT_LENGTH + NONCE_LENGTH)
+        .ok_or(invalid_data("not an encrypted input"))?;
+    let (salt, data) = data.split_at(SALT_LENGTH);
+    let (nonce, ciphertext) = data.split_at(NONCE_LENGTH);
+
+    Aes256Gcm::new(&secret.key(salt)?)
+        .decrypt(Nonce::from_slice(nonce), ciphertext)
+        .map_err(|_| invalid_data("wrong passphrase or key file, or the file is damaged"))
+}
+
+/// Writes `input.txt.enc` for the input `path`
+pub fn write_encrypted(path: &Path, content: &str, secret: &Secret) -> io::Result<()> {
+    fs::write(encrypted_path(path), encrypt(content.as_bytes(), secret)?)
+}
+
+/// Reads the input, or decrypts `input.txt.enc` if there is no plain input
+pub fn read_input(path: &Path, secret: Option<&Secret>) -> io::Result<String> {
+    match fs::read_to_string(path) {
+        Err(err) if err.kind() == io::ErrorKind::NotFound => {}
+        result => return result,
+    }
+    let data = fs::read(encrypted_path(path))?;
+    let secret = secret.ok_or(io::Error::new(
+        io::ErrorKind::PermissionDenied,
+        format!("the input is encrypted, set {PASSPHRASE_ENV_VAR} or pass --key-file"),
+    ))?;
+    String::from_utf8(decrypt(&data, secret)?).map_err(|_| invalid_data("the input is not UTF-8"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encrypt_and_decrypt() {
+        let secret = Secret::Passphrase(String::from("correct horse"));
+        let data = encrypt(b"1 2\n3 4\n", &secret).unwrap();
+        assert!(data.starts_with(MAGIC));
+        assert_eq!(decrypt(&data, &secret).unwrap(), b"1 2\n3 4\n");
+
+        let wrong = Secret::Passphrase(String::from("battery staple"));
+        assert!(decrypt(&data, &wrong).is_err());
+        assert!(decrypt(b"1 2\n", &secret).is_err());
+    }
+
+    #[test]
+    fn test_read_input() {
+        let dir = std::env::temp_dir().join(format!("start_coding_store_{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let key_file = dir.join("key");
+        fs::write(&key_file, "secret\n").unwrap();
+        let secret = Secret::KeyFile(key_file);
+        let path = dir.join("input.txt");
+
+        write_encrypted(&path, "(()\n", &secret).unwrap();
+        assert_eq!(encrypted_path(&path), dir.join("input.txt.enc"));
+        assert_eq!(read_input(&path, Some(&secret)).unwrap(), "(()\n");
+        assert!(read_input(&path, None).is_err());
+
+        fs::write(&path, "plain").unwrap();
+        assert_eq!(read_input(&path, None).unwrap(), "plain");
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
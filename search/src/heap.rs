@@ -44,11 +44,15 @@ pub struct MinHeap<K: Ord, V> {
 
 impl<K: Ord, V> MinHeap<K, V> {
     pub fn new() -> Self {
-        MinHeap { heap: BinaryHeap::new() }
+        MinHeap {
+            heap: BinaryHeap::new(),
+        }
     }
 
     pub fn with_capacity(capacity: usize) -> Self {
-        MinHeap { heap: BinaryHeap::with_capacity(capacity) }
+        MinHeap {
+            heap: BinaryHeap::with_capacity(capacity),
+        }
     }
 
     #[inline]
@@ -65,4 +69,53 @@ impl<K: Ord, V> MinHeap<K, V> {
     pub fn peek(&self) -> Option<(&K, &V)> {
         self.heap.peek().map(|w| (&w.key, &w.value))
     }
-}
\ No newline at end of file
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.heap.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.heap.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.heap.clear();
+    }
+
+    /// The items by increasing key
+    pub fn into_sorted_vec(self) -> Vec<(K, V)> {
+        // the wrappers are ordered by decreasing key
+        self.heap
+            .into_sorted_vec()
+            .into_iter()
+            .rev()
+            .map(|w| (w.key, w.value))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_min_heap() {
+        let mut heap = MinHeap::new();
+        assert!(heap.is_empty());
+        for (key, value) in [(3, 'c'), (1, 'a'), (2, 'b')] {
+            heap.push(key, value);
+        }
+        assert_eq!(heap.len(), 3);
+        assert_eq!(heap.peek(), Some((&1, &'a')));
+        assert_eq!(heap.pop(), Some((1, 'a')));
+        heap.push(0, 'z');
+        assert_eq!(heap.into_sorted_vec(), [(0, 'z'), (2, 'b'), (3, 'c')]);
+
+        let mut heap = MinHeap::with_capacity(1);
+        heap.push(1, ());
+        heap.clear();
+        assert_eq!(heap.pop(), None);
+    }
+}
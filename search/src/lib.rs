@@ -0,0 +1,328 @@
+//! Searches on any graph, the nodes are whatever the puzzle needs (points of a grid, states with a
+//! direction, ...) and the edges come from a neighbour function.
+use std::collections::hash_map::Entry;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+pub mod heap;
+
+pub use heap::MinHeap;
+
+/// The nodes reached from the start, with the cost of their shortest paths and every node they
+/// are reached from on one of these paths
+pub struct Paths<N, C> {
+    start: N,
+    costs: HashMap<N, C>,
+    parents: HashMap<N, Vec<N>>,
+}
+
+impl<N: Eq + Hash + Clone, C: Copy> Paths<N, C> {
+    fn new(start: N, zero: C) -> Self {
+        Self {
+            costs: HashMap::from([(start.clone(), zero)]),
+            parents: HashMap::new(),
+            start,
+        }
+    }
+
+    pub fn start(&self) -> &N {
+        &self.start
+    }
+
+    /// Cost of the shortest paths to the node, `None` if it can't be reached
+    pub fn cost(&self, node: &N) -> Option<C> {
+        self.costs.get(node).copied()
+    }
+
+    /// The reached nodes with their cost, the start included
+    pub fn reached(&self) -> impl Iterator<Item = (&N, C)> {
+        self.costs.iter().map(|(node, &cost)| (node, cost))
+    }
+
+    /// The nodes the node is reached from on its shortest paths
+    pub fn parents(&self, node: &N) -> &[N] {
+        self.parents.get(node).map_or(&[], Vec::as_slice)
+    }
+
+    /// One of the shortest paths from the start to the node, both included
+    pub fn path_to(&self, node: &N) -> Option<Vec<N>> {
+        self.costs.get(node)?;
+        let mut path = vec![node.clone()];
+        while let Some(parent) = self.parents(path.last().unwrap()).first() {
+            path.push(parent.clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    /// Every shortest path from the start to the node
+    pub fn all_paths_to(&self, node: &N) -> Vec<Vec<N>> {
+        if !self.costs.contains_key(node) {
+            return vec![];
+        }
+        if *node == self.start {
+            return vec![vec![node.clone()]];
+        }
+        let mut paths = vec![];
+        for parent in self.parents(node) {
+            for mut path in self.all_paths_to(parent) {
+                path.push(node.clone());
+                paths.push(path);
+            }
+        }
+        paths
+    }
+
+    /// Number of shortest paths from the start to the node, without listing them
+    pub fn count_paths_to(&self, node: &N) -> usize {
+        fn count<N: Eq + Hash + Clone, C: Copy>(
+            paths: &Paths<N, C>,
+            node: &N,
+            counts: &mut HashMap<N, usize>,
+        ) -> usize {
+            if *node == paths.start {
+                return 1;
+            }
+            if let Some(&n) = counts.get(node) {
+                return n;
+            }
+            let n = paths
+                .parents(node)
+                .iter()
+                .map(|parent| count(paths, parent, counts))
+                .sum();
+            counts.insert(node.clone(), n);
+            n
+        }
+
+        if !self.costs.contains_key(node) {
+            return 0;
+        }
+        count(self, node, &mut HashMap::new())
+    }
+
+    /// The nodes that are on at least one of the shortest paths to the node
+    pub fn nodes_on_paths_to(&self, node: &N) -> HashSet<N> {
+        let mut nodes = HashSet::new();
+        if !self.costs.contains_key(node) {
+            return nodes;
+        }
+        let mut stack = vec![node.clone()];
+        while let Some(node) = stack.pop() {
+            if nodes.insert(node.clone()) {
+                stack.extend(self.parents(&node).iter().cloned());
+            }
+        }
+        nodes
+    }
+}
+
+/// Breadth-first search of every node reachable from the start, the cost is the number of steps
+pub fn bfs<N, I>(start: N, mut neighbours: impl FnMut(&N) -> I) -> Paths<N, usize>
+where
+    N: Eq + Hash + Clone,
+    I: IntoIterator<Item = N>,
+{
+    let mut paths = Paths::new(start.clone(), 0);
+    let mut queue = VecDeque::from([(start, 0)]);
+    while let Some((node, steps)) = queue.pop_front() {
+        for next in neighbours(&node) {
+            match paths.costs.entry(next.clone()) {
+                Entry::Vacant(entry) => {
+                    entry.insert(steps + 1);
+                    paths.parents.insert(next.clone(), vec![node.clone()]);
+                    queue.push_back((next, steps + 1));
+                }
+                Entry::Occupied(entry) if *entry.get() == steps + 1 => {
+                    paths.parents.entry(next).or_default().push(node.clone());
+                }
+                Entry::Occupied(_) => {}
+            }
+        }
+    }
+    paths
+}
+
+/// Dijkstra's search of every node reachable from the start, `neighbours` gives the next nodes
+/// with the cost of the edge to them. The costs can't be negative, `C::default()` is zero.
+/// A node gets no more parents once it is settled, so the edges of cost zero can't make the
+/// parents loop.
+pub fn dijkstra<N, C, I>(start: N, mut neighbours: impl FnMut(&N) -> I) -> Paths<N, C>
+where
+    N: Eq + Hash + Clone,
+    C: Ord + Copy + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut paths = Paths::new(start.clone(), C::default());
+    let mut done = HashSet::new();
+    let mut heap = MinHeap::new();
+    heap.push(C::default(), start);
+    while let Some((cost, node)) = heap.pop() {
+        if !done.insert(node.clone()) {
+            continue;
+        }
+        for (next, edge) in neighbours(&node) {
+            if next == paths.start || done.contains(&next) {
+                continue;
+            }
+            let next_cost = cost + edge;
+            match paths.costs.get(&next) {
+                Some(&best) if best < next_cost => {}
+                Some(&best) if best == next_cost => {
+                    paths.parents.entry(next).or_default().push(node.clone());
+                }
+                _ => {
+                    paths.costs.insert(next.clone(), next_cost);
+                    paths.parents.insert(next.clone(), vec![node.clone()]);
+                    heap.push(next_cost, next);
+                }
+            }
+        }
+    }
+    paths
+}
+
+/// A* search of the cheapest path to a goal, returned with its cost. The heuristic estimates
+/// the cost left to a goal and must never overestimate it.
+pub fn astar<N, C, I>(
+    start: N,
+    mut neighbours: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Option<(C, Vec<N>)>
+where
+    N: Eq + Hash + Clone,
+    C: Ord + Copy + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut costs = HashMap::from([(start.clone(), C::default())]);
+    let mut parents = HashMap::new();
+    let mut heap = MinHeap::new();
+    heap.push(heuristic(&start), (C::default(), start));
+    while let Some((_, (cost, node))) = heap.pop() {
+        if is_goal(&node) {
+            return Some((cost, reconstruct_path(&parents, node)));
+        }
+        if costs.get(&node).is_some_and(|&best| best < cost) {
+            continue;
+        }
+        for (next, edge) in neighbours(&node) {
+            let next_cost = cost + edge;
+            if costs.get(&next).is_some_and(|&best| best <= next_cost) {
+                continue;
+            }
+            costs.insert(next.clone(), next_cost);
+            parents.insert(next.clone(), node.clone());
+            heap.push(next_cost + heuristic(&next), (next_cost, next));
+        }
+    }
+    None
+}
+
+/// Dijkstra's search of the cheapest path to a goal, returned with its cost
+pub fn dijkstra_to<N, C, I>(
+    start: N,
+    neighbours: impl FnMut(&N) -> I,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Option<(C, Vec<N>)>
+where
+    N: Eq + Hash + Clone,
+    C: Ord + Copy + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    astar(start, neighbours, |_| C::default(), is_goal)
+}
+
+/// The path from the start to the node by following the parent of each node, both included
+pub fn reconstruct_path<N: Eq + Hash + Clone>(parents: &HashMap<N, N>, node: N) -> Vec<N> {
+    let mut path = vec![node];
+    while let Some(parent) = parents.get(path.last().unwrap()) {
+        path.push(parent.clone());
+    }
+    path.reverse();
+    path
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// a -1- b -1- d
+    ///  \-2- c -0-/ and e unreachable
+    fn graph(node: &char) -> Vec<(char, u32)> {
+        match node {
+            'a' => vec![('b', 1), ('c', 2)],
+            'b' => vec![('d', 1)],
+            'c' => vec![('d', 0)],
+            _ => vec![],
+        }
+    }
+
+    #[test]
+    fn test_bfs() {
+        // a 3x3 grid without its middle
+        let neighbours = |&(row, col): &(i32, i32)| {
+            [(0, 1), (1, 0), (0, -1), (-1, 0)]
+                .map(|(dr, dc)| (row + dr, col + dc))
+                .into_iter()
+                .filter(|&(r, c)| (0..3).contains(&r) && (0..3).contains(&c) && (r, c) != (1, 1))
+        };
+        let paths = bfs((0, 0), neighbours);
+        assert_eq!(paths.cost(&(2, 2)), Some(4));
+        assert_eq!(paths.cost(&(1, 1)), None);
+        assert_eq!(paths.reached().count(), 8);
+        assert_eq!(paths.count_paths_to(&(2, 2)), 2);
+        assert_eq!(paths.path_to(&(0, 2)), Some(vec![(0, 0), (0, 1), (0, 2)]));
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        let paths = dijkstra('a', graph);
+        assert_eq!(paths.cost(&'d'), Some(2));
+        assert_eq!(paths.cost(&'e'), None);
+        let mut all = paths.all_paths_to(&'d');
+        all.sort();
+        assert_eq!(all, [vec!['a', 'b', 'd'], vec!['a', 'c', 'd']]);
+        assert_eq!(paths.count_paths_to(&'d'), 2);
+        assert_eq!(paths.nodes_on_paths_to(&'d').len(), 4);
+        assert_eq!(paths.all_paths_to(&'a'), [vec!['a']]);
+    }
+
+    #[test]
+    fn test_dijkstra_zero_cost_cycle() {
+        // s <-0-> a <-0-> b, and b -1-> s
+        let graph = |node: &char| match node {
+            's' => vec![('a', 0)],
+            'a' => vec![('s', 0), ('b', 0)],
+            'b' => vec![('a', 0), ('s', 1)],
+            _ => vec![],
+        };
+        let paths = dijkstra('s', graph);
+        assert_eq!(paths.parents(&'s'), []);
+        assert_eq!(paths.parents(&'a'), ['s']);
+        assert_eq!(paths.path_to(&'b'), Some(vec!['s', 'a', 'b']));
+        assert_eq!(paths.all_paths_to(&'b'), [vec!['s', 'a', 'b']]);
+        assert_eq!(paths.count_paths_to(&'a'), 1);
+        assert_eq!(paths.nodes_on_paths_to(&'b').len(), 3);
+    }
+
+    #[test]
+    fn test_astar() {
+        assert_eq!(
+            dijkstra_to('a', graph, |&node| node == 'd').map(|(cost, _)| cost),
+            Some(2)
+        );
+        assert_eq!(dijkstra_to('a', graph, |&node| node == 'e'), None);
+
+        // on a line, the heuristic is the distance left
+        let goal = 10;
+        let result = astar(
+            0,
+            |&n: &i32| [(n - 1, 1), (n + 1, 1)],
+            |&n| (goal - n).unsigned_abs(),
+            |&n| n == goal,
+        );
+        assert_eq!(result, Some((10, (0..=10).collect())));
+    }
+}
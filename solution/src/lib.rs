@@ -0,0 +1,176 @@
+//! The `Solution` trait implemented by every day, so that they can all be run the same way: by
+//! their own binary reading the input from stdin, or by the `aoc` runner.
+use std::error::Error;
+use std::fmt;
+use std::fmt::Display;
+use std::io::Read;
+use std::process::exit;
+use std::time::{Duration, Instant};
+
+pub trait Solution: Sized {
+    type Answer1: Display;
+    type Answer2: Display;
+
+    fn parse(input: &str) -> Result<Self, ParseError>;
+
+    fn part1(&self) -> Self::Answer1;
+
+    fn part2(&self) -> Self::Answer2;
+}
+
+/// Why the input could not be parsed. Any error converts into it, so the parsers can use `?`.
+pub struct ParseError(String);
+
+impl ParseError {
+    pub fn new(message: impl Into<String>) -> Self {
+        Self(message.into())
+    }
+}
+
+impl<E: Error> From<E> for ParseError {
+    fn from(err: E) -> Self {
+        Self(err.to_string())
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl fmt::Debug for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "ParseError({:?})", self.0)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub fn number(self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Answer {
+    pub part: Part,
+    pub value: String,
+    pub time: Duration,
+}
+
+/// The answers of a day, with the time taken by each step
+#[derive(Debug, Clone, PartialEq)]
+pub struct Solved {
+    pub parse_time: Duration,
+    pub answers: Vec<Answer>,
+}
+
+/// `solve::<Day>`, a day whose answers are already turned into text
+pub type Solver = fn(&str, Option<Part>) -> Result<Solved, ParseError>;
+
+fn timed<T>(f: impl FnOnce() -> T) -> (T, Duration) {
+    let start = Instant::now();
+    let result = f();
+    (result, start.elapsed())
+}
+
+/// Parses the input and computes the given part, or both
+pub fn solve<S: Solution>(input: &str, part: Option<Part>) -> Result<Solved, ParseError> {
+    let (solution, parse_time) = timed(|| S::parse(input));
+    let solution = solution?;
+
+    let mut answers = vec![];
+    if part != Some(Part::Two) {
+        let (value, time) = timed(|| solution.part1().to_string());
+        answers.push(Answer {
+            part: Part::One,
+            value,
+            time,
+        });
+    }
+    if part != Some(Part::One) {
+        let (value, time) = timed(|| solution.part2().to_string());
+        answers.push(Answer {
+            part: Part::Two,
+            value,
+            time,
+        });
+    }
+    Ok(Solved {
+        parse_time,
+        answers,
+    })
+}
+
+/// `main` of a day: reads the input from stdin and prints both answers
+pub fn main<S: Solution>() {
+    let mut input = String::new();
+    if let Err(err) = std::io::stdin().read_to_string(&mut input) {
+        eprintln!("Error: Failed to read from stdin: {err}");
+        exit(1);
+    }
+    match solve::<S>(&input, None) {
+        Ok(solved) => {
+            for answer in solved.answers {
+                println!("Part {}: {}", answer.part.number(), answer.value);
+            }
+        }
+        Err(err) => {
+            eprintln!("Error: Invalid input, {err}");
+            exit(1);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Sum(Vec<i64>);
+
+    impl Solution for Sum {
+        type Answer1 = i64;
+        type Answer2 = usize;
+
+        fn parse(input: &str) -> Result<Self, ParseError> {
+            let numbers = input
+                .split_whitespace()
+                .map(str::parse)
+                .collect::<Result<_, _>>()?;
+            Ok(Sum(numbers))
+        }
+
+        fn part1(&self) -> i64 {
+            self.0.iter().sum()
+        }
+
+        fn part2(&self) -> usize {
+            self.0.len()
+        }
+    }
+
+    #[test]
+    fn test_solve() {
+        let values = |solved: Solved| -> Vec<String> {
+            solved.answers.into_iter().map(|a| a.value).collect()
+        };
+        assert_eq!(values(solve::<Sum>("1 2 3", None).unwrap()), ["6", "3"]);
+        assert_eq!(
+            values(solve::<Sum>("1 2 3", Some(Part::Two)).unwrap()),
+            ["3"]
+        );
+        assert_eq!(
+            solve::<Sum>("1 x", None).unwrap_err().to_string(),
+            "invalid digit found in string"
+        );
+    }
+}
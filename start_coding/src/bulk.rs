@@ -0,0 +1,226 @@
+//! Downloads the inputs of a whole event. The requests are shared by a few workers, spaced by a
+//! minimum delay and retried with an exponential backoff when the server fails.
+use crate::cache::{self, CachePolicy, InputCache};
+use crate::error::ScrapeError;
+use crate::fetcher::ReqwestFetcher;
+use crate::session::Session;
+use crate::{calendar, default_base_url, input, last_day, user_agent, AutoInputScraper};
+use std::fmt;
+use std::fmt::{Display, Formatter};
+use std::sync::Mutex;
+use std::thread;
+use std::time::{Duration, Instant};
+
+pub struct FetchOptions {
+    /// Number of requests running at the same time
+    pub jobs: usize,
+    /// Minimum time between two requests
+    pub delay: Duration,
+    /// Number of retries when the server fails
+    pub retries: u32,
+    /// Wait before the first retry, doubled for each next one
+    pub backoff: Duration,
+    pub user_agent: String,
+    pub base_url: String,
+}
+
+impl Default for FetchOptions {
+    fn default() -> Self {
+        Self {
+            jobs: 2,
+            delay: Duration::from_secs(1),
+            retries: 3,
+            backoff: Duration::from_secs(2),
+            user_agent: user_agent(None),
+            base_url: default_base_url(),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum DayStatus {
+    Fetched,
+    Cached,
+    Failed(ScrapeError),
+}
+
+impl Display for DayStatus {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            DayStatus::Fetched => write!(f, "fetched"),
+            DayStatus::Cached => write!(f, "cached"),
+            DayStatus::Failed(err) => write!(f, "failed: {err}"),
+        }
+    }
+}
+
+/// Spaces the requests of all the workers by a minimum delay
+struct Throttle {
+    delay: Duration,
+    next: Mutex<Instant>,
+}
+
+impl Throttle {
+    fn new(delay: Duration) -> Self {
+        Self {
+            delay,
+            next: Mutex::new(Instant::now()),
+        }
+    }
+
+    /// Waits for the turn of the next request, the other workers wait behind it
+    fn wait(&self) {
+        let mut next = self.next.lock().unwrap();
+        let now = Instant::now();
+        if *next > now {
+            thread::sleep(*next - now);
+        }
+        *next = Instant::now() + self.delay;
+    }
+}
+
+fn backoff_delay(backoff: Duration, attempt: u32) -> Duration {
+    backoff.saturating_mul(2u32.saturating_pow(attempt))
+}
+
+fn is_server_error(err: &ScrapeError) -> bool {
+    matches!(err, ScrapeError::HttpStatus(500..=599))
+}
+
+/// Days of the event that are unlocked by now
+pub fn unlocked_days(year: i32) -> Vec<u32> {
+    (1..=last_day(year))
+        .filter(|&day| calendar::is_unlocked(year, day))
+        .collect()
+}
+
+fn fetch_day(
+    year: i32,
+    day: u32,
+    session: &Session,
+    cache: &InputCache,
+    options: &FetchOptions,
+    throttle: &Throttle,
+) -> DayStatus {
+    // inputs cached by older versions may be login pages, they are downloaded again
+    match cache.get(&cache::account_key(&session.token), year, day) {
+        Ok(Some(content)) if input::validate(&content).is_ok() => return DayStatus::Cached,
+        Ok(_) => {}
+        Err(err) => return DayStatus::Failed(err.into()),
+    }
+
+    let scraper = AutoInputScraper::new(year, day, Box::new(session.clone()))
+        .with_fetcher(Box::new(ReqwestFetcher::new(&options.user_agent)))
+        .with_base_url(&options.base_url);
+    let mut attempt = 0;
+    loop {
+        throttle.wait();
+        match scraper.get_input(cache, CachePolicy::Refresh) {
+            Ok(_) => return DayStatus::Fetched,
+            Err(err) if is_server_error(&err) && attempt < options.retries => {
+                thread::sleep(backoff_delay(options.backoff, attempt));
+                attempt += 1;
+            }
+            Err(err) => return DayStatus::Failed(err),
+        }
+    }
+}
+
+/// Downloads the inputs of the days that are not cached yet, the statuses are sorted by day
+pub fn fetch_all(
+    year: i32,
+    days: &[u32],
+    session: &Session,
+    cache: &InputCache,
+    options: &FetchOptions,
+) -> Vec<(u32, DayStatus)> {
+    let throttle = Throttle::new(options.delay);
+    let queue = Mutex::new(days.iter().copied());
+    let statuses = Mutex::new(vec![]);
+
+    thread::scope(|scope| {
+        for _ in 0..options.jobs.max(1) {
+            scope.spawn(|| loop {
+                let next = queue.lock().unwrap().next();
+                let Some(day) = next else {
+                    break;
+                };
+                let status = fetch_day(year, day, session, cache, options, &throttle);
+                statuses.lock().unwrap().push((day, status));
+            });
+        }
+    });
+
+    let mut statuses = statuses.into_inner().unwrap();
+    statuses.sort_by_key(|(day, _)| *day);
+    statuses
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_backoff() {
+        let backoff = Duration::from_secs(2);
+        assert_eq!(backoff_delay(backoff, 0), Duration::from_secs(2));
+        assert_eq!(backoff_delay(backoff, 3), Duration::from_secs(16));
+        assert!(is_server_error(&ScrapeError::HttpStatus(503)));
+        assert!(!is_server_error(&ScrapeError::HttpStatus(404)));
+        assert!(!is_server_error(&ScrapeError::RateLimited));
+    }
+
+    #[test]
+    fn test_throttle() {
+        let throttle = Throttle::new(Duration::from_millis(20));
+        let start = Instant::now();
+        for _ in 0..3 {
+            throttle.wait();
+        }
+        assert!(start.elapsed() >= Duration::from_millis(40));
+    }
+
+    #[test]
+    fn test_skip_cached_days() {
+        let dir = std::env::temp_dir().join(format!("start_coding_bulk_{}", std::process::id()));
+        let cache = InputCache::new(dir.clone());
+        let session = Session {
+            token: String::from("token"),
+            source: String::from("test"),
+        };
+        for day in 1..=3 {
+            cache
+                .put(&cache::account_key(&session.token), 2015, day, "()")
+                .unwrap();
+        }
+
+        let statuses = fetch_all(2015, &[3, 1, 2], &session, &cache, &FetchOptions::default());
+        assert_eq!(
+            statuses
+                .iter()
+                .map(|(day, status)| format!("{day} {status}"))
+                .collect::<Vec<_>>(),
+            ["1 cached", "2 cached", "3 cached"]
+        );
+
+        // a login page in the cache is not an input, it is downloaded again
+        cache
+            .put(
+                &cache::account_key(&session.token),
+                2015,
+                4,
+                "<!DOCTYPE html><p>Please log in</p>",
+            )
+            .unwrap();
+        let options = FetchOptions {
+            delay: Duration::ZERO,
+            retries: 0,
+            base_url: String::from("http://127.0.0.1:1"),
+            ..FetchOptions::default()
+        };
+        let statuses = fetch_all(2015, &[4], &session, &cache, &options);
+        assert!(matches!(statuses[0], (4, DayStatus::Failed(_))));
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
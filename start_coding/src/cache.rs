@@ -0,0 +1,129 @@
+//! Content-addressed store of the downloaded inputs. The inputs are saved under their SHA-256
+//! in `objects/` and `index/{account}/{year}/{day}` points to the input of the account.
+use crate::paths;
+use sha2::{Digest, Sha256};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CachePolicy {
+    /// Uses the cached input and downloads it only if it is missing
+    Default,
+    /// Never touches the network, a missing input is an error
+    Offline,
+    /// Downloads the input again and replaces the cached one
+    Refresh,
+}
+
+pub fn sha256_hex(data: &[u8]) -> String {
+    Sha256::digest(data)
+        .iter()
+        .map(|byte| format!("{byte:02x}"))
+        .collect()
+}
+
+/// Identifies the account without storing its session token
+pub fn account_key(token: &str) -> String {
+    sha256_hex(token.as_bytes())[..16].to_owned()
+}
+
+pub struct InputCache {
+    dir: PathBuf,
+}
+
+impl InputCache {
+    pub fn new(dir: PathBuf) -> Self {
+        Self { dir }
+    }
+
+    /// The cache in `$XDG_CACHE_HOME/start_coding`, or `~/.cache/start_coding`
+    pub fn open_default() -> Option<Self> {
+        paths::cache_dir().map(Self::new)
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    fn index_path(&self, account: &str, year: i32, day: u32) -> PathBuf {
+        self.dir
+            .join("index")
+            .join(account)
+            .join(year.to_string())
+            .join(day.to_string())
+    }
+
+    fn object_path(&self, hash: &str) -> PathBuf {
+        self.dir.join("objects").join(hash)
+    }
+
+    pub fn contains(&self, account: &str, year: i32, day: u32) -> bool {
+        self.index_path(account, year, day).exists()
+    }
+
+    /// Returns the cached input, `None` if it is missing or doesn't match its hash
+    pub fn get(&self, account: &str, year: i32, day: u32) -> io::Result<Option<String>> {
+        let hash = match fs::read_to_string(self.index_path(account, year, day)) {
+            Ok(hash) => hash.trim().to_owned(),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(None),
+            Err(err) => return Err(err),
+        };
+        let content = match fs::read_to_string(self.object_path(&hash)) {
+            Ok(content) => content,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(None),
+            Err(err) => return Err(err),
+        };
+
+        Ok((sha256_hex(content.as_bytes()) == hash).then_some(content))
+    }
+
+    pub fn put(&self, account: &str, year: i32, day: u32, content: &str) -> io::Result<()> {
+        let hash = sha256_hex(content.as_bytes());
+        let object_path = self.object_path(&hash);
+        if !object_path.exists() {
+            fs::create_dir_all(self.dir.join("objects"))?;
+            fs::write(object_path, content)?;
+        }
+
+        let index_path = self.index_path(account, year, day);
+        if let Some(dir) = index_path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(index_path, hash)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sha256() {
+        assert_eq!(
+            sha256_hex(b"abc"),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+    }
+
+    #[test]
+    fn test_put_and_get() {
+        let dir = std::env::temp_dir().join(format!("start_coding_cache_{}", std::process::id()));
+        let cache = InputCache::new(dir.clone());
+        let account = account_key("token");
+
+        assert_eq!(cache.get(&account, 2024, 1).unwrap(), None);
+        cache.put(&account, 2024, 1, "1 2\n").unwrap();
+        assert!(cache.contains(&account, 2024, 1));
+        assert_eq!(
+            cache.get(&account, 2024, 1).unwrap(),
+            Some(String::from("1 2\n"))
+        );
+        assert_eq!(cache.get(&account_key("other"), 2024, 1).unwrap(), None);
+
+        fs::write(cache.object_path(&sha256_hex(b"1 2\n")), "changed").unwrap();
+        assert_eq!(cache.get(&account, 2024, 1).unwrap(), None);
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,97 @@
+//! Puzzles unlock at midnight in the US-Eastern time zone, so every date is computed there
+//! instead of in the local time.
+use crate::{last_day, FIRST_YEAR};
+use chrono::{DateTime, Datelike, TimeZone, Utc};
+use chrono_tz::America::New_York;
+use chrono_tz::Tz;
+use std::time::Duration;
+
+pub fn now() -> DateTime<Tz> {
+    Utc::now().with_timezone(&New_York)
+}
+
+/// Time when the puzzle of the day is unlocked
+pub fn unlock_time(year: i32, day: u32) -> DateTime<Tz> {
+    New_York
+        .with_ymd_and_hms(year, 12, day, 0, 0, 0)
+        .single()
+        .expect("Invalid puzzle date.")
+}
+
+pub fn is_unlocked_at(year: i32, day: u32, time: DateTime<Tz>) -> bool {
+    time >= unlock_time(year, day)
+}
+
+pub fn is_unlocked(year: i32, day: u32) -> bool {
+    is_unlocked_at(year, day, now())
+}
+
+/// The puzzle unlocked on the day of the time, if there is one
+pub fn puzzle_of_day(time: DateTime<Tz>) -> Option<(i32, u32)> {
+    let (year, day) = (time.year(), time.day());
+    (time.month() == 12 && year >= FIRST_YEAR && day <= last_day(year)).then_some((year, day))
+}
+
+/// The year of the latest event that has started by the time
+pub fn latest_year(time: DateTime<Tz>) -> i32 {
+    if time.month() == 12 {
+        time.year()
+    } else {
+        time.year() - 1
+    }
+}
+
+/// The first puzzle unlocked after the time
+pub fn next_unlock(time: DateTime<Tz>) -> (i32, u32) {
+    let year = time.year();
+    if let Some((year, day)) = puzzle_of_day(time) {
+        if day < last_day(year) {
+            return (year, day + 1);
+        }
+    }
+    if time < unlock_time(year, 1) {
+        (year, 1)
+    } else {
+        (year + 1, 1)
+    }
+}
+
+/// Time left until the puzzle unlocks, `None` if it is already unlocked
+pub fn time_until_unlock(year: i32, day: u32) -> Option<Duration> {
+    (unlock_time(year, day) - now()).to_std().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn est(month: u32, day: u32, hour: u32) -> DateTime<Tz> {
+        New_York
+            .with_ymd_and_hms(2024, month, day, hour, 0, 0)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_unlock_time_is_midnight_est() {
+        assert_eq!(
+            unlock_time(2024, 1).with_timezone(&Utc).to_rfc3339(),
+            "2024-12-01T05:00:00+00:00"
+        );
+        assert!(!is_unlocked_at(2024, 2, est(12, 1, 23)));
+        assert!(is_unlocked_at(2024, 2, est(12, 2, 0)));
+    }
+
+    #[test]
+    fn test_puzzle_of_day() {
+        assert_eq!(puzzle_of_day(est(12, 5, 0)), Some((2024, 5)));
+        assert_eq!(puzzle_of_day(est(12, 26, 0)), None);
+        assert_eq!(puzzle_of_day(est(11, 30, 23)), None);
+    }
+
+    #[test]
+    fn test_next_unlock() {
+        assert_eq!(next_unlock(est(11, 30, 23)), (2024, 1));
+        assert_eq!(next_unlock(est(12, 5, 1)), (2024, 6));
+        assert_eq!(next_unlock(est(12, 25, 1)), (2025, 1));
+    }
+}
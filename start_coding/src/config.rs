@@ -0,0 +1,156 @@
+//! Defaults of the command line options, read from `~/.config/start_coding/config.toml` and
+//! from the nearest `.start_coding.toml` of the current directory or its parents. The options
+//! given on the command line win over the repository file, which wins over the user file.
+use crate::input::TrailingNewline;
+use crate::scaffold::Dependency;
+use crate::session::SessionSource;
+use crate::{paths, Browsers};
+use serde::Deserialize;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+pub const CONFIG_FILE_NAME: &str = "config.toml";
+pub const LOCAL_CONFIG_FILE_NAME: &str = ".start_coding.toml";
+
+#[derive(Deserialize, Debug, Default, Clone, PartialEq)]
+#[serde(default, deny_unknown_fields, rename_all = "kebab-case")]
+pub struct Config {
+    pub browser: Option<Browsers>,
+    pub session_source: Option<SessionSource>,
+    pub year: Option<i32>,
+    /// Year of the `dayN` packages in the root, made before the year directories
+    pub legacy_year: Option<i32>,
+    pub file_name: Option<String>,
+    pub trailing_newline: Option<TrailingNewline>,
+    /// Directory with the package template
+    pub template: Option<PathBuf>,
+    /// Directory where the `YEAR/dayN` packages and the workspace are created
+    pub output_root: Option<PathBuf>,
+    /// Contact added to the User-Agent
+    pub contact: Option<String>,
+    pub base_url: Option<String>,
+    /// Stores the inputs encrypted as `input.txt.enc`
+    pub encrypt: Option<bool>,
+    /// File with the secret the inputs are encrypted with
+    pub key_file: Option<PathBuf>,
+    /// Dependencies of the new packages, as `NAME=VERSION`
+    pub dependencies: Option<Vec<Dependency>>,
+}
+
+impl Config {
+    /// Reads the file, the relative paths in it are relative to its directory.
+    /// `None` if the file doesn't exist.
+    pub fn load(path: &Path) -> io::Result<Option<Self>> {
+        let content = match fs::read_to_string(path) {
+            Ok(content) => content,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(None),
+            Err(err) => return Err(err),
+        };
+        let mut config: Self = toml::from_str(&content).map_err(|err| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("{}: {err}", path.display()),
+            )
+        })?;
+
+        let dir = path.parent().unwrap_or(Path::new(""));
+        for path in [
+            &mut config.template,
+            &mut config.output_root,
+            &mut config.key_file,
+        ]
+        .into_iter()
+        .flatten()
+        {
+            if path.is_relative() {
+                *path = dir.join(&*path);
+            }
+        }
+        Ok(Some(config))
+    }
+
+    /// The settings of `other` override the ones of `self`
+    pub fn merge(self, other: Self) -> Self {
+        Self {
+            browser: other.browser.or(self.browser),
+            session_source: other.session_source.or(self.session_source),
+            year: other.year.or(self.year),
+            legacy_year: other.legacy_year.or(self.legacy_year),
+            file_name: other.file_name.or(self.file_name),
+            trailing_newline: other.trailing_newline.or(self.trailing_newline),
+            template: other.template.or(self.template),
+            output_root: other.output_root.or(self.output_root),
+            contact: other.contact.or(self.contact),
+            base_url: other.base_url.or(self.base_url),
+            encrypt: other.encrypt.or(self.encrypt),
+            key_file: other.key_file.or(self.key_file),
+            dependencies: other.dependencies.or(self.dependencies),
+        }
+    }
+
+    /// The nearest `.start_coding.toml` of the directory or its parents
+    pub fn find_local(dir: &Path) -> Option<PathBuf> {
+        dir.ancestors()
+            .map(|dir| dir.join(LOCAL_CONFIG_FILE_NAME))
+            .find(|path| path.is_file())
+    }
+
+    /// The user config merged with the repository config of the current directory
+    pub fn load_default() -> io::Result<Self> {
+        let mut config = Self::default();
+        if let Some(path) = paths::config_dir().map(|dir| dir.join(CONFIG_FILE_NAME)) {
+            config = config.merge(Self::load(&path)?.unwrap_or_default());
+        }
+        if let Some(path) = Self::find_local(&std::env::current_dir()?) {
+            config = config.merge(Self::load(&path)?.unwrap_or_default());
+        }
+        Ok(config)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_load_and_merge() {
+        let dir = std::env::temp_dir().join(format!("start_coding_config_{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let user = dir.join(CONFIG_FILE_NAME);
+        fs::write(
+            &user,
+            "browser = \"firefox\"\nfile-name = \"data\"\nsession-source = \"cookies-txt\"\n",
+        )
+        .unwrap();
+        let local = dir.join(LOCAL_CONFIG_FILE_NAME);
+        fs::write(
+            &local,
+            "year = 2023\nfile-name = \"input\"\noutput-root = \"aoc\"\ndependencies = [\"regex=1.11.1\"]\n",
+        )
+        .unwrap();
+
+        let config = Config::load(&user)
+            .unwrap()
+            .unwrap()
+            .merge(Config::load(&local).unwrap().unwrap());
+        assert!(matches!(config.browser, Some(Browsers::Firefox)));
+        assert_eq!(config.session_source, Some(SessionSource::CookiesTxt));
+        assert_eq!(config.year, Some(2023));
+        assert_eq!(config.file_name.as_deref(), Some("input"));
+        assert_eq!(config.output_root, Some(dir.join("aoc")));
+        assert_eq!(
+            config.dependencies,
+            Some(vec!["regex=1.11.1".parse().unwrap()])
+        );
+        assert_eq!(Config::find_local(&dir.join("aoc")), Some(local.clone()));
+
+        fs::write(&local, "colour = \"red\"\n").unwrap();
+        assert!(Config::load(&local).is_err());
+        fs::write(&local, "dependencies = [\"=1\"]\n").unwrap();
+        assert!(Config::load(&local).is_err());
+        assert_eq!(Config::load(&dir.join("missing.toml")).unwrap(), None);
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
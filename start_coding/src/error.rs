@@ -0,0 +1,87 @@
+use std::fmt;
+use std::fmt::{Display, Formatter};
+use std::io;
+
+#[derive(Debug)]
+pub enum ScrapeError {
+    /// No Advent of Code session was found in the described source
+    MissingSession(String),
+    /// The server asked to log in, the session cookie is not valid anymore
+    ExpiredSession,
+    /// The puzzle is not unlocked yet
+    NotUnlocked,
+    /// Too many requests were sent to the server
+    RateLimited,
+    /// The server responded with an unexpected HTTP status
+    HttpStatus(u16),
+    /// The response doesn't look like anything the server should return
+    UnexpectedResponse,
+    /// The request could not be sent or its body read
+    Request(reqwest::Error),
+    /// The downloaded input doesn't look like an input, for the described reason
+    InvalidInput(String),
+    /// The input is not in the cache and the network is not allowed
+    NotCached,
+    /// Reading or writing the local files failed
+    Io(io::Error),
+}
+
+impl Display for ScrapeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            ScrapeError::MissingSession(source) => {
+                write!(f, "AOC session not found in {source}.")
+            }
+            ScrapeError::ExpiredSession => {
+                write!(
+                    f,
+                    "The session has expired, log in to Advent of Code again."
+                )
+            }
+            ScrapeError::NotUnlocked => write!(f, "The puzzle is not unlocked yet."),
+            ScrapeError::RateLimited => {
+                write!(
+                    f,
+                    "Too many requests were sent, wait a while before trying again."
+                )
+            }
+            ScrapeError::HttpStatus(status) => {
+                write!(f, "The server responded with HTTP status {status}.")
+            }
+            ScrapeError::UnexpectedResponse => write!(f, "Unrecognized response from the server."),
+            ScrapeError::Request(err) => write!(f, "HTTP request failed: {err}"),
+            ScrapeError::InvalidInput(reason) => {
+                write!(f, "The downloaded input is not valid, {reason}.")
+            }
+            ScrapeError::NotCached => {
+                write!(
+                    f,
+                    "The input is not cached, run without --offline to download it."
+                )
+            }
+            ScrapeError::Io(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl std::error::Error for ScrapeError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ScrapeError::Request(err) => Some(err),
+            ScrapeError::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<reqwest::Error> for ScrapeError {
+    fn from(err: reqwest::Error) -> Self {
+        ScrapeError::Request(err)
+    }
+}
+
+impl From<io::Error> for ScrapeError {
+    fn from(err: io::Error) -> Self {
+        ScrapeError::Io(err)
+    }
+}
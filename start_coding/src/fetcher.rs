@@ -0,0 +1,65 @@
+//! The HTTP requests sent to Advent of Code go through the `Fetcher` trait, so that the scraper
+//! can be pointed at another implementation or a local server in the tests.
+use crate::error::ScrapeError;
+use reqwest::blocking::Client;
+
+/// Status and body of a response, the scraper decides what they mean
+#[derive(Debug, Clone, PartialEq)]
+pub struct Response {
+    pub status: u16,
+    pub body: String,
+}
+
+pub trait Fetcher {
+    fn get(&self, url: &str, cookie: &str) -> Result<Response, ScrapeError>;
+
+    /// Sends the form URL-encoded
+    fn post_form(
+        &self,
+        url: &str,
+        cookie: &str,
+        form: &[(&str, &str)],
+    ) -> Result<Response, ScrapeError>;
+}
+
+pub struct ReqwestFetcher {
+    client: Client,
+}
+
+impl ReqwestFetcher {
+    pub fn new(user_agent: &str) -> Self {
+        let client = Client::builder()
+            .user_agent(user_agent)
+            .build()
+            .expect("Failed to create the HTTP client");
+        Self { client }
+    }
+
+    fn read(response: reqwest::blocking::Response) -> Result<Response, ScrapeError> {
+        Ok(Response {
+            status: response.status().as_u16(),
+            body: response.text()?,
+        })
+    }
+}
+
+impl Fetcher for ReqwestFetcher {
+    fn get(&self, url: &str, cookie: &str) -> Result<Response, ScrapeError> {
+        Self::read(self.client.get(url).header("Cookie", cookie).send()?)
+    }
+
+    fn post_form(
+        &self,
+        url: &str,
+        cookie: &str,
+        form: &[(&str, &str)],
+    ) -> Result<Response, ScrapeError> {
+        Self::read(
+            self.client
+                .post(url)
+                .header("Cookie", cookie)
+                .form(form)
+                .send()?,
+        )
+    }
+}
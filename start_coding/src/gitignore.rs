@@ -0,0 +1,91 @@
+//! Keeps the puzzle inputs out of git with a block of `.gitignore` that `start_coding` rewrites,
+//! the rest of the file is left as it is. The patterns already in the block are kept, so the
+//! inputs saved with another file name stay ignored.
+use std::fs;
+use std::io;
+use std::path::Path;
+
+pub const GITIGNORE_FILE_NAME: &str = ".gitignore";
+
+const BLOCK_START: &str = "# >>> start_coding: puzzle inputs must not be published";
+const BLOCK_END: &str = "# <<< start_coding";
+
+/// The block listing the patterns
+fn block(patterns: &[&str]) -> String {
+    let mut block = format!("{BLOCK_START}\n");
+    for pattern in patterns {
+        block.push_str(&format!("{pattern}\n"));
+    }
+    block.push_str(&format!("{BLOCK_END}\n"));
+    block
+}
+
+/// The content with the block listing its current patterns followed by the new ones
+fn with_block(content: &str, patterns: &[String]) -> String {
+    match (content.find(BLOCK_START), content.find(BLOCK_END)) {
+        (Some(start), Some(end)) if start < end => {
+            let mut merged: Vec<&str> = content[start + BLOCK_START.len()..end]
+                .lines()
+                .map(str::trim)
+                .filter(|line| !line.is_empty())
+                .collect();
+            for pattern in patterns {
+                if !merged.contains(&pattern.as_str()) {
+                    merged.push(pattern);
+                }
+            }
+            let rest = content[end + BLOCK_END.len()..].trim_start_matches('\n');
+            format!("{}{}{rest}", &content[..start], block(&merged))
+        }
+        _ => {
+            let patterns: Vec<&str> = patterns.iter().map(String::as_str).collect();
+            if content.is_empty() || content.ends_with('\n') {
+                format!("{content}{}", block(&patterns))
+            } else {
+                format!("{content}\n{}", block(&patterns))
+            }
+        }
+    }
+}
+
+/// Adds or updates the block in the `.gitignore` of the directory, returns `true` if the file
+/// was changed
+pub fn ensure_ignored(dir: &Path, patterns: &[String]) -> io::Result<bool> {
+    let path = dir.join(GITIGNORE_FILE_NAME);
+    let content = match fs::read_to_string(&path) {
+        Ok(content) => content,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => String::new(),
+        Err(err) => return Err(err),
+    };
+    let updated = with_block(&content, patterns);
+    if updated == content {
+        return Ok(false);
+    }
+    fs::write(path, updated)?;
+    Ok(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_with_block() {
+        let patterns = [String::from("input.txt")];
+        let content = with_block("target/", &patterns);
+        assert_eq!(
+            content,
+            format!("target/\n{BLOCK_START}\ninput.txt\n{BLOCK_END}\n")
+        );
+        assert_eq!(with_block(&content, &patterns), content);
+
+        // a run with another file name keeps the inputs saved before ignored
+        let content = format!("{content}Cargo.lock\n");
+        let content = with_block(&content, &[String::from("data.txt")]);
+        assert_eq!(
+            content,
+            format!("target/\n{BLOCK_START}\ninput.txt\ndata.txt\n{BLOCK_END}\nCargo.lock\n")
+        );
+        assert_eq!(with_block(&content, &patterns), content);
+    }
+}
@@ -0,0 +1,165 @@
+use crate::submit::SubmitOutcome;
+use chrono::Local;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::{fs, io};
+
+pub const HISTORY_FILE_NAME: &str = "answers.json";
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Attempt {
+    pub year: i32,
+    pub day: u32,
+    pub part: u8,
+    pub answer: String,
+    pub outcome: SubmitOutcome,
+    pub timestamp: i64,
+}
+
+/// Every answer submitted for a day, stored next to the day package
+#[derive(Debug, Default)]
+pub struct SubmissionHistory {
+    path: PathBuf,
+    attempts: Vec<Attempt>,
+}
+
+impl SubmissionHistory {
+    pub fn load(dir: &Path) -> io::Result<Self> {
+        let path = dir.join(HISTORY_FILE_NAME);
+        let attempts = match fs::read_to_string(&path) {
+            Ok(content) => serde_json::from_str(&content)?,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => vec![],
+            Err(err) => return Err(err),
+        };
+
+        Ok(Self { path, attempts })
+    }
+
+    pub fn save(&self) -> io::Result<()> {
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(&self.path, serde_json::to_string_pretty(&self.attempts)?)
+    }
+
+    pub fn attempts(&self, year: i32, day: u32, part: u8) -> impl Iterator<Item = &Attempt> {
+        self.attempts
+            .iter()
+            .filter(move |a| a.year == year && a.day == day && a.part == part)
+    }
+
+    pub fn record(&mut self, year: i32, day: u32, part: u8, answer: &str, outcome: SubmitOutcome) {
+        self.attempts.push(Attempt {
+            year,
+            day,
+            part,
+            answer: answer.to_owned(),
+            outcome,
+            timestamp: Local::now().timestamp(),
+        });
+    }
+
+    /// Returns the outcome the answer would get based on the previous attempts,
+    /// or `None` if it is worth submitting
+    pub fn predict(&self, year: i32, day: u32, part: u8, answer: &str) -> Option<SubmitOutcome> {
+        let mut lower_bound: Option<i64> = None;
+        let mut upper_bound: Option<i64> = None;
+
+        for attempt in self.attempts(year, day, part) {
+            match attempt.outcome {
+                SubmitOutcome::Correct => return Some(SubmitOutcome::AlreadySolved),
+                SubmitOutcome::TooHigh | SubmitOutcome::TooLow | SubmitOutcome::Wrong
+                    if attempt.answer == answer =>
+                {
+                    return Some(attempt.outcome.clone());
+                }
+                _ => {}
+            }
+
+            if let Ok(value) = attempt.answer.parse::<i64>() {
+                match attempt.outcome {
+                    SubmitOutcome::TooHigh => {
+                        upper_bound = Some(upper_bound.map_or(value, |b| b.min(value)))
+                    }
+                    SubmitOutcome::TooLow => {
+                        lower_bound = Some(lower_bound.map_or(value, |b| b.max(value)))
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        let value: i64 = answer.parse().ok()?;
+        if upper_bound.is_some_and(|bound| value >= bound) {
+            Some(SubmitOutcome::TooHigh)
+        } else if lower_bound.is_some_and(|bound| value <= bound) {
+            Some(SubmitOutcome::TooLow)
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn history(attempts: &[(&str, SubmitOutcome)]) -> SubmissionHistory {
+        let mut history = SubmissionHistory::default();
+        for (answer, outcome) in attempts {
+            history.record(2024, 1, 1, answer, outcome.clone());
+        }
+        history
+    }
+
+    #[test]
+    fn test_predict_known_answers() {
+        let history = history(&[
+            ("abc", SubmitOutcome::Wrong),
+            ("100", SubmitOutcome::RateLimited(None)),
+        ]);
+        assert_eq!(
+            history.predict(2024, 1, 1, "abc"),
+            Some(SubmitOutcome::Wrong)
+        );
+        assert_eq!(history.predict(2024, 1, 1, "100"), None);
+        assert_eq!(history.predict(2024, 1, 2, "abc"), None);
+        assert_eq!(history.predict(2023, 1, 1, "abc"), None);
+    }
+
+    #[test]
+    fn test_predict_bounds() {
+        let history = history(&[
+            ("500", SubmitOutcome::TooHigh),
+            ("300", SubmitOutcome::TooHigh),
+            ("100", SubmitOutcome::TooLow),
+            ("50", SubmitOutcome::TooLow),
+        ]);
+        assert_eq!(
+            history.predict(2024, 1, 1, "400"),
+            Some(SubmitOutcome::TooHigh)
+        );
+        assert_eq!(
+            history.predict(2024, 1, 1, "300"),
+            Some(SubmitOutcome::TooHigh)
+        );
+        assert_eq!(
+            history.predict(2024, 1, 1, "100"),
+            Some(SubmitOutcome::TooLow)
+        );
+        assert_eq!(
+            history.predict(2024, 1, 1, "75"),
+            Some(SubmitOutcome::TooLow)
+        );
+        assert_eq!(history.predict(2024, 1, 1, "200"), None);
+    }
+
+    #[test]
+    fn test_predict_solved() {
+        let history = history(&[("42", SubmitOutcome::Correct)]);
+        assert_eq!(
+            history.predict(2024, 1, 1, "43"),
+            Some(SubmitOutcome::AlreadySolved)
+        );
+    }
+}
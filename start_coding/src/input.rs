@@ -0,0 +1,144 @@
+//! Checks of the downloaded input before it is written, and the `.sha256` file written next to
+//! it in the format of `sha256sum`, so that local edits can be detected later.
+use crate::cache::sha256_hex;
+use crate::error::ScrapeError;
+use clap::ValueEnum;
+use serde::Deserialize;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+pub const CHECKSUM_EXTENSION: &str = "sha256";
+
+/// Number of characters at the start of the body where the error pages are recognized
+const PREFIX_LEN: usize = 200;
+
+#[derive(ValueEnum, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+pub enum TrailingNewline {
+    /// Keeps the input as it was downloaded
+    Keep,
+    /// Removes the newlines at the end of the input
+    Strip,
+}
+
+/// Turns the pages that the server returns instead of an input into errors
+pub fn validate(body: &str) -> Result<(), ScrapeError> {
+    let prefix = body
+        .trim_start()
+        .chars()
+        .take(PREFIX_LEN)
+        .collect::<String>()
+        .to_lowercase();
+    if body.contains("Puzzle inputs differ by user") || body.contains("Please log in") {
+        Err(ScrapeError::ExpiredSession)
+    } else if prefix.contains("rate limit") || prefix.contains("too many requests") {
+        Err(ScrapeError::RateLimited)
+    } else if prefix.starts_with("<!doctype html") || prefix.starts_with("<html") {
+        Err(ScrapeError::InvalidInput(String::from(
+            "the server returned an HTML page",
+        )))
+    } else if prefix.is_empty() {
+        Err(ScrapeError::InvalidInput(String::from("it is empty")))
+    } else {
+        Ok(())
+    }
+}
+
+/// Converts the line endings to `\n`
+pub fn normalize_line_endings(input: &str) -> String {
+    input.replace("\r\n", "\n").replace('\r', "\n")
+}
+
+pub fn apply_trailing_newline(input: &str, trailing_newline: TrailingNewline) -> &str {
+    match trailing_newline {
+        TrailingNewline::Keep => input,
+        TrailingNewline::Strip => input.trim_end_matches('\n'),
+    }
+}
+
+/// `input.txt.sha256` for `input.txt`
+pub fn checksum_path(path: &Path) -> PathBuf {
+    let mut name = path.file_name().unwrap_or_default().to_owned();
+    name.push(format!(".{CHECKSUM_EXTENSION}"));
+    path.with_file_name(name)
+}
+
+/// Writes the input and its checksum
+pub fn write(path: &Path, content: &str) -> io::Result<()> {
+    fs::write(path, content)?;
+    let name = path.file_name().unwrap_or_default().to_string_lossy();
+    fs::write(
+        checksum_path(path),
+        format!("{}  {name}\n", sha256_hex(content.as_bytes())),
+    )
+}
+
+/// Checks the input against its checksum, `None` if one of them is missing
+pub fn verify(path: &Path) -> io::Result<Option<bool>> {
+    let (content, checksum) = match (fs::read(path), fs::read_to_string(checksum_path(path))) {
+        (Ok(content), Ok(checksum)) => (content, checksum),
+        (Err(err), _) | (_, Err(err)) if err.kind() == io::ErrorKind::NotFound => return Ok(None),
+        (Err(err), _) | (_, Err(err)) => return Err(err),
+    };
+    let hash = checksum.split_whitespace().next().unwrap_or_default();
+    Ok(Some(sha256_hex(&content) == hash))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate() {
+        assert!(validate("1 2\n3 4\n").is_ok());
+        assert!(matches!(
+            validate("Puzzle inputs differ by user.  Please log in to get your puzzle input.\n"),
+            Err(ScrapeError::ExpiredSession)
+        ));
+        assert!(matches!(
+            validate("\n<!DOCTYPE html>\n<html lang=\"en-us\">"),
+            Err(ScrapeError::InvalidInput(_))
+        ));
+        assert!(matches!(validate(" \n"), Err(ScrapeError::InvalidInput(_))));
+        assert!(matches!(
+            validate("Too Many Requests"),
+            Err(ScrapeError::RateLimited)
+        ));
+        let input = format!("{}rate limit\n", "1 2\n".repeat(100));
+        assert!(validate(&input).is_ok());
+    }
+
+    #[test]
+    fn test_normalize() {
+        assert_eq!(normalize_line_endings("1\r\n2\r3\n"), "1\n2\n3\n");
+        assert_eq!(
+            apply_trailing_newline("1\n2\n", TrailingNewline::Strip),
+            "1\n2"
+        );
+        assert_eq!(
+            apply_trailing_newline("1\n2\n", TrailingNewline::Keep),
+            "1\n2\n"
+        );
+    }
+
+    #[test]
+    fn test_checksum() {
+        let dir = std::env::temp_dir().join(format!("start_coding_input_{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("input.txt");
+        assert_eq!(checksum_path(&path), dir.join("input.txt.sha256"));
+        assert_eq!(verify(&path).unwrap(), None);
+
+        write(&path, "abc").unwrap();
+        assert_eq!(
+            fs::read_to_string(checksum_path(&path)).unwrap(),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad  input.txt\n"
+        );
+        assert_eq!(verify(&path).unwrap(), Some(true));
+        fs::write(&path, "abd").unwrap();
+        assert_eq!(verify(&path).unwrap(), Some(false));
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
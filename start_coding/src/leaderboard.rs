@@ -0,0 +1,237 @@
+//! Private leaderboards, read from `/{year}/leaderboard/private/view/{id}.json`. Advent of Code
+//! asks to request the JSON at most once every 15 minutes, so it is cached that long.
+use crate::{last_day, paths};
+use chrono::DateTime;
+use chrono_tz::America::New_York;
+use serde::Deserialize;
+use std::cmp::Reverse;
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+pub const CACHE_DURATION: Duration = Duration::from_secs(15 * 60);
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct Star {
+    pub get_star_ts: i64,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct Member {
+    pub id: u64,
+    pub name: Option<String>,
+    pub stars: u32,
+    pub local_score: u64,
+    pub last_star_ts: i64,
+    /// Stars of each day, by day and then by part
+    #[serde(default)]
+    pub completion_day_level: BTreeMap<u32, BTreeMap<u8, Star>>,
+}
+
+impl Member {
+    /// The name, or how Advent of Code shows the members without one
+    pub fn display_name(&self) -> String {
+        self.name
+            .clone()
+            .unwrap_or(format!("(anonymous user #{})", self.id))
+    }
+
+    pub fn star(&self, day: u32, part: u8) -> Option<&Star> {
+        self.completion_day_level.get(&day)?.get(&part)
+    }
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct Leaderboard {
+    pub event: String,
+    pub members: BTreeMap<String, Member>,
+}
+
+impl Leaderboard {
+    pub fn parse(json: &str) -> serde_json::Result<Self> {
+        serde_json::from_str(json)
+    }
+
+    /// Members by local score, then by stars, then by who got the last star first
+    pub fn ranking(&self) -> Vec<&Member> {
+        let mut members: Vec<&Member> = self.members.values().collect();
+        members.sort_by_key(|m| (Reverse(m.local_score), Reverse(m.stars), m.last_star_ts));
+        members
+    }
+
+    /// Table of the members with the stars of each day: `*` both parts, `+` the first one
+    pub fn render(&self, year: i32) -> String {
+        let ranking = self.ranking();
+        let width = ranking
+            .iter()
+            .map(|m| m.display_name().chars().count())
+            .max()
+            .unwrap_or(0)
+            .max(4);
+
+        let mut result = format!(
+            "{:>4}  {:<width$}  {:>5}  {:>5}  Days\n",
+            "Rank", "Name", "Stars", "Score"
+        );
+        for (rank, member) in ranking.iter().enumerate() {
+            let days: String = (1..=last_day(year))
+                .map(|day| match (member.star(day, 1), member.star(day, 2)) {
+                    (Some(_), Some(_)) => '*',
+                    (Some(_), None) => '+',
+                    _ => '.',
+                })
+                .collect();
+            writeln!(
+                result,
+                "{:>4}  {:<width$}  {:>5}  {:>5}  {days}",
+                rank + 1,
+                member.display_name(),
+                member.stars,
+                member.local_score
+            )
+            .unwrap();
+        }
+
+        result.push_str("\nCompletion times (US-Eastern)\n");
+        for member in ranking {
+            if member.completion_day_level.is_empty() {
+                continue;
+            }
+            writeln!(result, "{}", member.display_name()).unwrap();
+            for day in member.completion_day_level.keys() {
+                let time = |part| {
+                    member
+                        .star(*day, part)
+                        .map(|star| format_timestamp(star.get_star_ts))
+                        .unwrap_or(String::from("-"))
+                };
+                writeln!(
+                    result,
+                    "  Day {day:>2}  part 1 {:<19}  part 2 {}",
+                    time(1),
+                    time(2)
+                )
+                .unwrap();
+            }
+        }
+        result
+    }
+}
+
+fn format_timestamp(timestamp: i64) -> String {
+    DateTime::from_timestamp(timestamp, 0)
+        .map(|time| {
+            time.with_timezone(&New_York)
+                .format("%Y-%m-%d %H:%M:%S")
+                .to_string()
+        })
+        .unwrap_or_default()
+}
+
+/// The downloaded JSON of the leaderboards, kept for `CACHE_DURATION`
+pub struct LeaderboardCache {
+    dir: PathBuf,
+}
+
+impl LeaderboardCache {
+    pub fn new(dir: PathBuf) -> Self {
+        Self { dir }
+    }
+
+    /// The cache in `$XDG_CACHE_HOME/start_coding/leaderboard`
+    pub fn open_default() -> Option<Self> {
+        paths::cache_dir().map(|dir| Self::new(dir.join("leaderboard")))
+    }
+
+    fn path(&self, account: &str, year: i32, id: u64) -> PathBuf {
+        self.dir
+            .join(account)
+            .join(year.to_string())
+            .join(format!("{id}.json"))
+    }
+
+    fn is_fresh(path: &Path) -> io::Result<bool> {
+        let age = fs::metadata(path)?
+            .modified()?
+            .elapsed()
+            .unwrap_or_default();
+        Ok(age < CACHE_DURATION)
+    }
+
+    /// Returns the JSON if it was downloaded less than `CACHE_DURATION` ago
+    pub fn get(&self, account: &str, year: i32, id: u64) -> io::Result<Option<String>> {
+        let path = self.path(account, year, id);
+        match Self::is_fresh(&path) {
+            Ok(true) => Ok(Some(fs::read_to_string(path)?)),
+            Ok(false) => Ok(None),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    pub fn put(&self, account: &str, year: i32, id: u64, json: &str) -> io::Result<()> {
+        let path = self.path(account, year, id);
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(path, json)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const JSON: &str = r#"{
+        "owner_id": 1, "event": "2024",
+        "members": {
+            "1": {"id": 1, "name": "Alice", "stars": 3, "local_score": 5, "global_score": 0,
+                  "last_star_ts": 1733030000,
+                  "completion_day_level": {
+                      "1": {"1": {"get_star_ts": 1733029500, "star_index": 1},
+                            "2": {"get_star_ts": 1733030000, "star_index": 2}},
+                      "2": {"1": {"get_star_ts": 1733116000, "star_index": 3}}}},
+            "2": {"id": 2, "name": null, "stars": 2, "local_score": 5, "global_score": 0,
+                  "last_star_ts": 1733029800, "completion_day_level": {}},
+            "3": {"id": 3, "name": "Bob", "stars": 4, "local_score": 8, "global_score": 0,
+                  "last_star_ts": 1733120000, "completion_day_level": {}}
+        }
+    }"#;
+
+    #[test]
+    fn test_ranking() {
+        let leaderboard = Leaderboard::parse(JSON).unwrap();
+        let names: Vec<String> = leaderboard
+            .ranking()
+            .iter()
+            .map(|m| m.display_name())
+            .collect();
+        assert_eq!(names, ["Bob", "Alice", "(anonymous user #2)"]);
+    }
+
+    #[test]
+    fn test_render() {
+        let table = Leaderboard::parse(JSON).unwrap().render(2024);
+        assert!(
+            table.contains("   2  Alice                    3      5  *+.......................")
+        );
+        assert!(table.contains("  Day  1  part 1 2024-12-01 00:05:00  part 2 2024-12-01 00:13:20"));
+        assert!(table.contains("  Day  2  part 1 2024-12-02 00:06:40  part 2 -"));
+    }
+
+    #[test]
+    fn test_cache() {
+        let dir = std::env::temp_dir().join(format!("start_coding_board_{}", std::process::id()));
+        let cache = LeaderboardCache::new(dir.clone());
+        assert_eq!(cache.get("account", 2024, 42).unwrap(), None);
+        cache.put("account", 2024, 42, JSON).unwrap();
+        assert_eq!(
+            cache.get("account", 2024, 42).unwrap().as_deref(),
+            Some(JSON)
+        );
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -1,12 +1,36 @@
-use chrono::{Datelike, Local};
+pub mod bulk;
+pub mod cache;
+pub mod calendar;
+pub mod config;
+pub mod error;
+pub mod fetcher;
+pub mod gitignore;
+pub mod history;
+pub mod input;
+pub mod leaderboard;
+pub mod paths;
+pub mod puzzle;
+pub mod scaffold;
+pub mod session;
+pub mod status;
+pub mod submit;
+pub mod workspace;
+
+use cache::{CachePolicy, InputCache};
 use clap::ValueEnum;
-use reqwest::blocking::Client;
-use rookie::{brave, chrome, firefox};
+use error::ScrapeError;
+use fetcher::{Fetcher, ReqwestFetcher, Response};
+use puzzle::Token;
+use serde::Deserialize;
+use session::{Session, SessionProvider};
+use std::cell::OnceCell;
 use std::fmt;
 use std::fmt::{Display, Formatter};
-use std::process::exit;
+use submit::SubmitOutcome;
+
 #[cfg(target_os = "macos")]
-#[derive(ValueEnum, Debug, Clone)]
+#[derive(ValueEnum, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "lowercase")]
 pub enum Browsers {
     Brave,
     Chrome,
@@ -15,7 +39,8 @@ pub enum Browsers {
 }
 
 #[cfg(not(target_os = "macos"))]
-#[derive(ValueEnum, Debug, Clone)]
+#[derive(ValueEnum, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "lowercase")]
 pub enum Browsers {
     Brave,
     Chrome,
@@ -34,61 +59,360 @@ impl Display for Browsers {
     }
 }
 
-const ADVENT_OF_CODE_WEBSITE: &str = "adventofcode.com";
+pub const ADVENT_OF_CODE_WEBSITE: &str = "adventofcode.com";
+
+pub const BASE_URL_ENV_VAR: &str = "AOC_BASE_URL";
+
+/// The URL in `AOC_BASE_URL`, to use another server
+pub fn base_url_from_env() -> Option<String> {
+    std::env::var(BASE_URL_ENV_VAR)
+        .ok()
+        .filter(|url| !url.is_empty())
+}
+
+/// The website, or the URL in `AOC_BASE_URL`
+pub fn default_base_url() -> String {
+    base_url_from_env().unwrap_or(format!("https://{ADVENT_OF_CODE_WEBSITE}"))
+}
+
+/// The first Advent of Code event
+pub const FIRST_YEAR: i32 = 2015;
+
+/// Last puzzle day of the event, since 2025 the events have only 12 days
+pub fn last_day(year: i32) -> u32 {
+    if year >= 2025 {
+        12
+    } else {
+        25
+    }
+}
+
+/// Checks that the event of the year has a puzzle for the day
+pub fn is_valid_date(year: i32, day: u32) -> bool {
+    year >= FIRST_YEAR && (1..=last_day(year)).contains(&day)
+}
+
+/// The User-Agent asked by the automation guidelines of Advent of Code, with a way to contact
+/// the user if one is given
+pub fn user_agent(contact: Option<&str>) -> String {
+    let agent = format!(
+        "start_coding/{} (Advent of Code input downloader)",
+        env!("CARGO_PKG_VERSION")
+    );
+    match contact {
+        Some(contact) => format!("{agent} contact: {contact}"),
+        None => agent,
+    }
+}
+
+/// The logged-in user from `<div class="user">NAME <span class="star-count">..</span></div>`,
+/// which is in the header of every page
+fn user_name(html: &str) -> Option<String> {
+    let mut in_user = false;
+    let mut name = String::new();
+    for token in puzzle::tokenize(html) {
+        match token {
+            Token::Open("div", attrs) if puzzle::get_attr(attrs, "class") == Some("user") => {
+                in_user = true
+            }
+            Token::Open(_, _) | Token::Close(_) if in_user => break,
+            Token::Text(text) if in_user => name.push_str(&text),
+            _ => {}
+        }
+    }
+    let name = name.trim();
+    (!name.is_empty()).then(|| name.to_owned())
+}
+
+/// The account name and where its session was found
+pub struct Account {
+    pub name: String,
+    pub source: String,
+}
+
+pub struct CachedInput {
+    pub content: String,
+    pub from_cache: bool,
+}
 
 pub struct AutoInputScraper {
     day: u32,
     year: i32,
+    provider: Box<dyn SessionProvider>,
+    /// The session of the provider, looked up once since the browser cookies are decrypted
+    /// each time
+    session: OnceCell<Session>,
+    fetcher: Box<dyn Fetcher>,
+    base_url: String,
 }
 
 impl AutoInputScraper {
-    pub fn new() -> Self {
-        let now = Local::now();
-
+    pub fn new(year: i32, day: u32, session: Box<dyn SessionProvider>) -> Self {
         Self {
-            day: now.day(),
-            year: now.year(),
+            day,
+            year,
+            provider: session,
+            session: OnceCell::new(),
+            fetcher: Box::new(ReqwestFetcher::new(&user_agent(None))),
+            base_url: default_base_url(),
         }
     }
 
-    pub fn get_result(&self, day: Option<u32>, browser: Browsers) -> String {
-        let domains = vec![String::from(ADVENT_OF_CODE_WEBSITE)];
-        let cookies = match browser {
-            #[cfg(target_os = "macos")]
-            Browsers::Safari => safari(Some(domains)),
-            Browsers::Brave => brave(Some(domains)),
-            Browsers::Chrome => chrome(Some(domains)),
-            Browsers::Firefox => firefox(Some(domains)),
-        };
+    pub fn with_fetcher(mut self, fetcher: Box<dyn Fetcher>) -> Self {
+        self.fetcher = fetcher;
+        self
+    }
+
+    pub fn with_base_url(mut self, base_url: &str) -> Self {
+        self.base_url = base_url.trim_end_matches('/').to_owned();
+        self
+    }
+
+    pub fn year(&self) -> i32 {
+        self.year
+    }
 
-        let cookies = cookies.unwrap_or_else(|_err| {
-            println!("AOC Cookies not found in {browser} browser.");
-            exit(0); // Exit if cookies are not found
-        });
+    pub fn day(&self) -> u32 {
+        self.day
+    }
+
+    /// The session of the provider, found on the first use and reused after
+    fn session(&self) -> Result<&Session, ScrapeError> {
+        if let Some(session) = self.session.get() {
+            return Ok(session);
+        }
+        let session = self.provider.session()?;
+        Ok(self.session.get_or_init(|| session))
+    }
+
+    fn get_session_cookie(&self) -> Result<String, ScrapeError> {
+        Ok(format!("session={}", self.session()?.token))
+    }
+
+    /// Where the session was found
+    pub fn session_source(&self) -> Result<String, ScrapeError> {
+        Ok(self.session()?.source.clone())
+    }
+
+    /// Key of the account in the input cache
+    pub fn account_key(&self) -> Result<String, ScrapeError> {
+        Ok(cache::account_key(&self.session()?.token))
+    }
+
+    pub fn base_url(&self) -> String {
+        self.base_url.clone()
+    }
+
+    fn day_url(&self) -> String {
+        format!("{}/{}/day/{}", self.base_url(), self.year, self.day)
+    }
 
-        let cookie = cookies.get(0).unwrap_or_else(|| {
-            println!("AOC Cookies not found in {browser} browser.");
-            exit(1); // Exit with status 1 if no cookie is found
-        });
+    /// Reads the body of the response and turns the known failures into errors
+    fn read_response(response: Response) -> Result<String, ScrapeError> {
+        let Response { status, body } = response;
 
-        let day_to_get = if let Some(day_changed) = day {
-            day_changed
+        if body.contains("Please don't repeatedly request this endpoint before it unlocks") {
+            Err(ScrapeError::NotUnlocked)
+        } else if body.contains("Please log in") || body.contains("/auth/login") {
+            Err(ScrapeError::ExpiredSession)
+        } else if status == 429 {
+            Err(ScrapeError::RateLimited)
+        } else if !(200..300).contains(&status) {
+            Err(ScrapeError::HttpStatus(status))
         } else {
-            self.day
+            Ok(body)
+        }
+    }
+
+    fn get_page(&self, address: String) -> Result<String, ScrapeError> {
+        let cookie = self.get_session_cookie()?;
+        Self::read_response(self.fetcher.get(&address, &cookie)?)
+    }
+
+    pub fn get_result(&self) -> Result<String, ScrapeError> {
+        self.get_page(format!("{}/input", self.day_url()))
+    }
+
+    /// Returns the input from the cache or downloads it, depending on the policy
+    pub fn get_input(
+        &self,
+        cache: &InputCache,
+        policy: CachePolicy,
+    ) -> Result<CachedInput, ScrapeError> {
+        let account = self.account_key()?;
+
+        if policy != CachePolicy::Refresh {
+            // inputs cached by older versions may be login pages
+            let cached = cache.get(&account, self.year, self.day)?;
+            if let Some(content) = cached.filter(|content| input::validate(content).is_ok()) {
+                return Ok(CachedInput {
+                    content,
+                    from_cache: true,
+                });
+            }
+            if policy == CachePolicy::Offline {
+                return Err(ScrapeError::NotCached);
+            }
+        }
+
+        let content = self.get_result()?;
+        input::validate(&content)?;
+        let content = input::normalize_line_endings(&content);
+        cache.put(&account, self.year, self.day, &content)?;
+        Ok(CachedInput {
+            content,
+            from_cache: false,
+        })
+    }
+
+    /// Returns the puzzle page, it contains the second part once the first one is solved
+    pub fn get_puzzle(&self) -> Result<String, ScrapeError> {
+        self.get_page(self.day_url())
+    }
+
+    /// The account of the session, read from the header of the settings page
+    pub fn whoami(&self) -> Result<Account, ScrapeError> {
+        let source = self.session_source()?;
+        let html = self.get_page(format!("{}/settings", self.base_url))?;
+        Ok(Account {
+            name: user_name(&html).ok_or(ScrapeError::ExpiredSession)?,
+            source,
+        })
+    }
+
+    /// The calendar of the year, it shows the stars of each day
+    pub fn get_calendar(&self) -> Result<String, ScrapeError> {
+        self.get_page(format!("{}/{}", self.base_url, self.year))
+    }
+
+    /// JSON of the private leaderboard of the year
+    pub fn get_leaderboard(&self, id: u64) -> Result<String, ScrapeError> {
+        self.get_page(format!(
+            "{}/{}/leaderboard/private/view/{id}.json",
+            self.base_url, self.year
+        ))
+    }
+
+    pub fn submit_answer(&self, part: u8, answer: &str) -> Result<SubmitOutcome, ScrapeError> {
+        let cookie = self.get_session_cookie()?;
+        let answer_address = format!("{}/answer", self.day_url());
+
+        let level = part.to_string();
+        let form = [("level", level.as_str()), ("answer", answer)];
+        let html = Self::read_response(self.fetcher.post_form(&answer_address, &cookie, &form)?)?;
+        SubmitOutcome::from_html(&html).ok_or(ScrapeError::UnexpectedResponse)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_valid_dates() {
+        assert!(is_valid_date(2015, 1));
+        assert!(is_valid_date(2024, 25));
+        assert!(is_valid_date(2025, 12));
+        assert!(!is_valid_date(2014, 1));
+        assert!(!is_valid_date(2024, 0));
+        assert!(!is_valid_date(2024, 26));
+        assert!(!is_valid_date(2025, 13));
+    }
+
+    /// Answers every request with the same response
+    struct FakeFetcher(Response);
+
+    impl Fetcher for FakeFetcher {
+        fn get(&self, _: &str, _: &str) -> Result<Response, ScrapeError> {
+            Ok(self.0.clone())
+        }
+
+        fn post_form(&self, _: &str, _: &str, _: &[(&str, &str)]) -> Result<Response, ScrapeError> {
+            Ok(self.0.clone())
+        }
+    }
+
+    fn scraper(status: u16, body: &str) -> AutoInputScraper {
+        let session = session::Session {
+            token: String::from("token"),
+            source: String::from("test"),
         };
+        AutoInputScraper::new(2015, 1, Box::new(session)).with_fetcher(Box::new(FakeFetcher(
+            Response {
+                status,
+                body: String::from(body),
+            },
+        )))
+    }
 
-        let input_address = format!(
-            "https://{}/{}/day/{}/input",
-            ADVENT_OF_CODE_WEBSITE, self.year, day_to_get
-        );
+    /// Counts the lookups of the session
+    struct CountingProvider(std::rc::Rc<std::cell::Cell<u32>>);
+
+    impl SessionProvider for CountingProvider {
+        fn describe(&self) -> String {
+            String::from("test")
+        }
 
-        let client = Client::new();
-        let response = client
-            .get(input_address)
-            .header("Cookie", format!("{}={}", cookie.name, cookie.value))
-            .send()
-            .expect("HTTP Request failed.");
+        fn session(&self) -> Result<Session, ScrapeError> {
+            self.0.set(self.0.get() + 1);
+            self.found("token")
+        }
+    }
 
-        response.text().expect("Failed to get the response text.")
+    #[test]
+    fn test_session_found_once() {
+        let lookups = std::rc::Rc::new(std::cell::Cell::new(0));
+        let scraper = AutoInputScraper::new(2015, 1, Box::new(CountingProvider(lookups.clone())))
+            .with_fetcher(Box::new(FakeFetcher(Response {
+                status: 200,
+                body: String::from("()"),
+            })));
+        scraper.account_key().unwrap();
+        scraper.get_result().unwrap();
+        scraper.session_source().unwrap();
+        assert_eq!(lookups.get(), 1);
+    }
+
+    #[test]
+    fn test_user_name() {
+        let html = r#"<header><div><h1 class="title-global"><a href="/">Advent of Code</a></h1>
+<div class="user">Alice <span class="star-count">42*</span></div></div></header>"#;
+        assert_eq!(user_name(html).as_deref(), Some("Alice"));
+        assert_eq!(
+            user_name(r#"<div class="user">(anonymous user #1234)</div>"#).as_deref(),
+            Some("(anonymous user #1234)")
+        );
+        assert_eq!(user_name("<p>[Log In]</p>"), None);
+        assert!(matches!(
+            scraper(200, "<p>[Log In]</p>").whoami(),
+            Err(ScrapeError::ExpiredSession)
+        ));
+    }
+
+    #[test]
+    fn test_read_response() {
+        assert_eq!(scraper(200, "()").get_result().unwrap(), "()");
+        assert!(matches!(
+            scraper(
+                400,
+                "Puzzle inputs differ by user.  Please log in to get your puzzle input."
+            )
+            .get_result(),
+            Err(ScrapeError::ExpiredSession)
+        ));
+        assert!(matches!(
+            scraper(429, "").get_result(),
+            Err(ScrapeError::RateLimited)
+        ));
+        assert!(matches!(
+            scraper(503, "").get_puzzle(),
+            Err(ScrapeError::HttpStatus(503))
+        ));
+        assert_eq!(
+            scraper(200, "<article><p>That's the right answer!</p></article>")
+                .submit_answer(1, "42")
+                .unwrap(),
+            SubmitOutcome::Correct
+        );
     }
 }
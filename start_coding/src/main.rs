@@ -1,19 +1,40 @@
-use chrono::{Datelike, Local};
-use clap::{arg, Parser};
-use start_coding::{AutoInputScraper, Browsers};
-use std::fs::File;
+use chrono::Local;
+use clap::{Parser, Subcommand};
+use puzzle_input::store::{self, Secret, PASSPHRASE_ENV_VAR};
+use start_coding::bulk::{self, DayStatus, FetchOptions};
+use start_coding::cache::{CachePolicy, InputCache};
+use start_coding::calendar;
+use start_coding::config::Config;
+use start_coding::error::ScrapeError;
+use start_coding::fetcher::ReqwestFetcher;
+use start_coding::gitignore;
+use start_coding::history::SubmissionHistory;
+use start_coding::input::{self, TrailingNewline};
+use start_coding::leaderboard::{Leaderboard, LeaderboardCache};
+use start_coding::puzzle::{self, Examples};
+use start_coding::scaffold::{self, Dependency, Template, TemplateContext, DEFAULT_DEPENDENCIES};
+use start_coding::session::{SessionProvider, SessionSource, COOKIES_FILE_NAME};
+use start_coding::status;
+use start_coding::submit::SubmitOutcome;
+use start_coding::workspace::{self, Workspace, MANIFEST_FILE_NAME};
+use start_coding::{
+    base_url_from_env, default_base_url, is_valid_date, last_day, user_agent, AutoInputScraper,
+    Browsers, FIRST_YEAR,
+};
 use std::io::Write;
-use std::path::Path;
-use std::process::{exit, Command};
+use std::path::{Path, PathBuf};
+use std::process::exit;
+use std::thread;
+use std::time::Duration;
 use std::{fs, io};
 
 /// A program that creates a Cargo package and downloads the current day's Advent of Code puzzle input
 #[derive(Parser, Debug)]
 #[command(version = "1.1", about)]
 struct Args {
-    /// Name of the file
-    #[arg(short, long, default_value_t = String::from("input"))]
-    file_name: String,
+    /// Name of the file [default: input]
+    #[arg(short, long)]
+    file_name: Option<String>,
 
     /// Download just the input
     #[arg(long, default_value_t = false)]
@@ -24,83 +45,802 @@ struct Args {
     test: bool,
 
     /// Specifies the puzzle day
-    #[arg(short, long)]
+    #[arg(short, long, global = true)]
     day: Option<u32>,
 
-    /// Specifies the browser to use
-    #[arg(long = "browser", short = 'b', value_name = "BROWSER", value_enum, default_value_t = Browsers::Brave)]
-    browser: Browsers,
+    /// Specifies the year of the event
+    #[arg(short, long, global = true, value_parser = clap::value_parser!(i32).range(FIRST_YEAR as i64..))]
+    year: Option<i32>,
+
+    /// Year of the dayN packages in the root, made before the YEAR/dayN directories
+    #[arg(long, value_name = "YEAR", global = true)]
+    legacy_year: Option<i32>,
+
+    /// Waits for the puzzle to unlock (the next one if no day is given) and then downloads it
+    #[arg(short, long, default_value_t = false)]
+    wait: bool,
+
+    /// Uses only the cached input and never touches the network
+    #[arg(long, default_value_t = false, conflicts_with = "refresh")]
+    offline: bool,
+
+    /// Downloads the input again even if it is cached
+    #[arg(long, default_value_t = false)]
+    refresh: bool,
+
+    /// Moves an existing package to YEAR/archive and creates it again
+    #[arg(long, default_value_t = false)]
+    backup: bool,
+
+    /// Answers yes to all questions
+    #[arg(long, visible_alias = "force", default_value_t = false)]
+    yes: bool,
+
+    /// Creates a standalone package instead of adding it to the workspace
+    #[arg(long, default_value_t = false)]
+    no_workspace: bool,
+
+    /// Directory with the template of the package, the built-in one is used by default
+    #[arg(long, value_name = "DIR")]
+    template: Option<PathBuf>,
+
+    /// Dependency added to the package, can be repeated [default: text_io=0.1.12]
+    #[arg(long = "dependency", value_name = "NAME[=VERSION]")]
+    dependencies: Vec<Dependency>,
+
+    /// Specifies where to look for the session [default: auto]
+    #[arg(long, value_name = "SOURCE", value_enum, global = true)]
+    session_source: Option<SessionSource>,
+
+    /// Specifies the browser to use [default: brave]
+    #[arg(
+        long = "browser",
+        short = 'b',
+        value_name = "BROWSER",
+        value_enum,
+        global = true
+    )]
+    browser: Option<Browsers>,
+
+    /// Cookies file in the Netscape format to import the session from
+    #[arg(long, value_name = "PATH", default_value = COOKIES_FILE_NAME, global = true)]
+    cookies_file: PathBuf,
+
+    /// Contact added to the User-Agent of the requests, e.g. an e-mail or a repository URL
+    #[arg(long, value_name = "CONTACT", global = true)]
+    contact: Option<String>,
+
+    /// URL of the Advent of Code server, also read from AOC_BASE_URL
+    #[arg(long, value_name = "URL", global = true)]
+    base_url: Option<String>,
+
+    /// Keeps or strips the newline at the end of the input [default: keep]
+    #[arg(long, value_name = "MODE", value_enum, global = true)]
+    trailing_newline: Option<TrailingNewline>,
+
+    /// Directory where the YEAR/dayN packages and the workspace are created [default: .]
+    #[arg(long, value_name = "DIR", global = true)]
+    output_root: Option<PathBuf>,
+
+    /// Stores the input encrypted as <FILE_NAME>.txt.enc, with the passphrase of
+    /// AOC_INPUT_PASSPHRASE or the key file
+    #[arg(long, default_value_t = false)]
+    encrypt: bool,
+
+    /// Stores the input as plain text, even with `encrypt = true` in the config
+    #[arg(long, default_value_t = false, conflicts_with = "encrypt")]
+    no_encrypt: bool,
+
+    /// File with the secret the inputs are encrypted with, instead of AOC_INPUT_PASSPHRASE
+    #[arg(long, value_name = "PATH", global = true)]
+    key_file: Option<PathBuf>,
+
+    #[command(subcommand)]
+    command: Option<Commands>,
+}
+
+#[derive(Subcommand, Debug)]
+enum Commands {
+    /// Submits an answer to the puzzle
+    Submit {
+        /// Part of the puzzle (1 or 2)
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+
+        /// The answer to submit
+        answer: String,
+    },
+    /// Downloads the inputs of every unlocked day of the year into the cache
+    FetchAll {
+        /// Number of downloads running at the same time
+        #[arg(long, default_value_t = 2, value_parser = clap::value_parser!(u8).range(1..=4))]
+        jobs: u8,
+
+        /// Minimum number of seconds between two requests
+        #[arg(long, default_value_t = 1.0)]
+        delay: f64,
+
+        /// Number of retries when the server fails
+        #[arg(long, default_value_t = 3)]
+        retries: u32,
+    },
+    /// Shows a private leaderboard of the year
+    Leaderboard {
+        /// ID of the leaderboard, the number in its URL
+        id: u64,
+
+        /// Prints the JSON of the leaderboard as it was downloaded
+        #[arg(long, default_value_t = false)]
+        json: bool,
+    },
+    /// Shows the account of the session and where the session was found
+    Whoami,
+    /// Shows the progress of each unlocked day of the year
+    Status,
+    /// Converts the day packages of the current directory into members of a Cargo workspace
+    Migrate,
+    /// Prints the input of the day, decrypting it if it is stored encrypted
+    Decrypt,
+}
+
+impl Args {
+    /// Fills the options missing on the command line with the config, the AOC_BASE_URL
+    /// environment variable comes before the config files
+    fn apply(&mut self, config: Config) {
+        self.browser = self.browser.or(config.browser);
+        self.session_source = self.session_source.or(config.session_source);
+        self.year = self.year.or(config.year);
+        self.legacy_year = self.legacy_year.or(config.legacy_year);
+        self.file_name = self.file_name.take().or(config.file_name);
+        self.template = self.template.take().or(config.template);
+        self.output_root = self.output_root.take().or(config.output_root);
+        self.contact = self.contact.take().or(config.contact);
+        self.trailing_newline = self.trailing_newline.or(config.trailing_newline);
+        self.encrypt = self.encrypt || (!self.no_encrypt && config.encrypt.unwrap_or(false));
+        self.key_file = self.key_file.take().or(config.key_file);
+        if self.dependencies.is_empty() {
+            self.dependencies = config.dependencies.unwrap_or_else(|| {
+                DEFAULT_DEPENDENCIES
+                    .iter()
+                    .map(|dependency| dependency.parse().unwrap())
+                    .collect()
+            });
+        }
+        self.base_url = self
+            .base_url
+            .take()
+            .or(base_url_from_env())
+            .or(config.base_url);
+    }
+
+    fn browser(&self) -> Browsers {
+        self.browser.unwrap_or(Browsers::Brave)
+    }
+
+    fn session_source(&self) -> SessionSource {
+        self.session_source.unwrap_or(SessionSource::Auto)
+    }
+
+    fn session_provider(&self) -> Box<dyn SessionProvider> {
+        self.session_source()
+            .provider(self.browser(), self.cookies_file.clone())
+    }
+
+    fn file_name(&self) -> &str {
+        self.file_name.as_deref().unwrap_or("input")
+    }
+
+    fn trailing_newline(&self) -> TrailingNewline {
+        self.trailing_newline.unwrap_or(TrailingNewline::Keep)
+    }
+
+    fn output_root(&self) -> &Path {
+        self.output_root.as_deref().unwrap_or(Path::new("."))
+    }
+
+    fn base_url(&self) -> String {
+        self.base_url.clone().unwrap_or_else(default_base_url)
+    }
+
+    fn user_agent(&self) -> String {
+        user_agent(self.contact.as_deref())
+    }
+
+    fn secret(&self) -> Option<Secret> {
+        Secret::find(self.key_file.as_deref())
+    }
 }
+
 const PACKAGE_NAME: &str = "day";
+const ARCHIVE_DIR_NAME: &str = "archive";
 
 fn main() {
-    let args = Args::parse();
+    let mut args = Args::parse();
+    let config = Config::load_default().unwrap_or_else(|err| {
+        eprintln!("Error: Failed to read the config: {err}");
+        exit(1);
+    });
+    args.apply(config);
+
+    if let Some(Commands::Migrate) = args.command {
+        migrate_workspace(args.output_root());
+        return;
+    }
+
+    if let Some(Commands::Whoami) = args.command {
+        whoami(&args);
+    }
 
-    let now = Local::now();
-    let day = if let Some(day) = args.day {
-        day
+    let now = calendar::now();
+    if let Some(Commands::FetchAll {
+        jobs,
+        delay,
+        retries,
+    }) = args.command
+    {
+        let options = FetchOptions {
+            jobs: jobs as usize,
+            delay: Duration::try_from_secs_f64(delay).unwrap_or_else(|_| {
+                eprintln!("Error: Invalid delay '{delay}'.");
+                exit(1);
+            }),
+            retries,
+            user_agent: args.user_agent(),
+            base_url: args.base_url(),
+            ..FetchOptions::default()
+        };
+        fetch_all(
+            &args,
+            args.year.unwrap_or(calendar::latest_year(now)),
+            &options,
+        );
+        return;
+    }
+    if let Some(Commands::Status) = args.command {
+        show_status(&args, args.year.unwrap_or(calendar::latest_year(now)));
+        return;
+    }
+    if let Some(Commands::Leaderboard { id, json }) = args.command {
+        show_leaderboard(
+            &args,
+            args.year.unwrap_or(calendar::latest_year(now)),
+            id,
+            json,
+        );
+        return;
+    }
+    let today = if args.wait {
+        Some(calendar::next_unlock(now))
     } else {
-        now.day()
+        calendar::puzzle_of_day(now)
     };
 
-    let new_package = format!(
-        "{}{PACKAGE_NAME}{day}",
+    let day = args.day.or(today.map(|(_, day)| day)).unwrap_or_else(|| {
+        eprintln!("Error: No puzzle unlocks today, specify the day with --day.");
+        exit(1);
+    });
+    let year = args
+        .year
+        .or(today.map(|(year, _)| year))
+        .unwrap_or(calendar::latest_year(now));
+
+    if !is_valid_date(year, day) {
+        eprintln!(
+            "Error: Advent of Code {year} has puzzles only for days 1 to {}.",
+            last_day(year)
+        );
+        exit(1);
+    }
+
+    if !calendar::is_unlocked(year, day) {
+        if args.wait {
+            wait_for_unlock(year, day);
+        } else {
+            eprintln!(
+                "Error: Day {day} of {year} is not unlocked yet, it unlocks in {}.",
+                format_duration(calendar::time_until_unlock(year, day).unwrap_or_default())
+            );
+            exit(1);
+        }
+    }
+
+    // the package relative to the output root, as it is listed in the workspace
+    let member = format!(
+        "{year}/{}{PACKAGE_NAME}{day}",
         if args.test { "test_" } else { "" }
     );
+    let new_package = match args.output_root() {
+        root if root == Path::new(".") => member.clone(),
+        root => root.join(&member).to_string_lossy().into_owned(),
+    };
+
+    if let Some(Commands::Decrypt) = args.command {
+        let file_path = Path::new(&new_package).join(format!("{}.txt", args.file_name()));
+        match store::read_input(&file_path, args.secret().as_ref()) {
+            Ok(content) => print!("{content}"),
+            Err(err) => {
+                eprintln!("Error: Failed to read '{}': {err}", file_path.display());
+                exit(1);
+            }
+        }
+        return;
+    }
+
+    let scraper = AutoInputScraper::new(year, day, args.session_provider())
+        .with_fetcher(Box::new(ReqwestFetcher::new(&args.user_agent())))
+        .with_base_url(&args.base_url());
+    if let Some(Commands::Submit { part, answer }) = &args.command {
+        submit(&scraper, *part, answer.trim(), &new_package);
+    }
+
     if new_package.is_empty() {
         eprintln!("Error: Package name cannot be empty.");
         exit(1);
     }
+
+    let cache = InputCache::open_default().unwrap_or_else(|| {
+        eprintln!("Error: The cache directory could not be determined, set XDG_CACHE_HOME.");
+        exit(1);
+    });
+    let policy = if args.offline {
+        CachePolicy::Offline
+    } else if args.refresh {
+        CachePolicy::Refresh
+    } else {
+        CachePolicy::Default
+    };
+    let input = scraper.get_input(&cache, policy).unwrap_or_else(|err| {
+        eprintln!("Error: {err}");
+        exit(1);
+    });
+    if input.from_cache {
+        println!("Using the cached input from '{}'", cache.dir().display());
+    }
+
+    let html = if args.offline {
+        None
+    } else {
+        fetch_puzzle(&scraper)
+    };
+
     if !args.no_package {
-        if !Path::new(&new_package).exists() {
-            let status = Command::new("cargo")
-                .arg("new")
-                .arg(&new_package)
-                .status()
-                .expect("Failed to execute command");
-            if status.success() {
-                println!("Successfully created package '{}'", new_package);
-            } else {
-                eprintln!("Failed to create package '{}'", new_package);
-                exit(1);
+        let package_dir = Path::new(&new_package);
+        // a directory with only the answers of `submit` is not a package yet
+        let package_exists = package_dir.join(MANIFEST_FILE_NAME).is_file();
+
+        if package_exists && args.backup {
+            let archive = archive_path(package_dir);
+            if !should_continue(
+                &format!(
+                    "The directory '{new_package}' will be moved to '{}' and created again.",
+                    archive.display()
+                ),
+                args.yes,
+            ) {
+                exit(0);
             }
-        } else if should_continue(&format!(
-            "The directory '{new_package}' already exists and will be removed."
-        )) {
-            if new_package.is_empty() {
-                eprintln!("Error: Package name cannot be empty.");
-                exit(1);
+            fs::create_dir_all(archive.parent().unwrap())
+                .and_then(|_| fs::rename(package_dir, &archive))
+                .unwrap_or_else(|err| {
+                    eprintln!("Error: Failed to move '{new_package}' to the archive: {err}");
+                    exit(1);
+                });
+            println!("Moved '{new_package}' to '{}'", archive.display());
+        } else if package_exists
+            && !should_continue(
+                &format!(
+                    "The directory '{new_package}' already exists, the downloaded files in it \
+                     will be refreshed and src/ will be kept."
+                ),
+                args.yes,
+            )
+        {
+            exit(0);
+        }
+
+        if !package_dir.join(MANIFEST_FILE_NAME).is_file() {
+            create_package(&args, year, day, &new_package, html.as_deref());
+            if !args.no_workspace {
+                register_in_workspace(args.output_root(), &member);
             }
-            fs::remove_dir_all(&new_package).expect("Failed to remove existing directory");
-            let status = Command::new("cargo")
-                .arg("init")
-                .arg(&new_package)
-                .status()
-                .expect("Failed to execute command");
-
-            if status.success() {
-                println!("Successfully initialized package '{}'", new_package);
-            } else {
-                eprintln!("Failed to initialize package '{}'", new_package);
+        }
+    }
+
+    let dir = format!("{new_package}/");
+    fs::create_dir_all(&dir).unwrap();
+    let file_path = Path::new(&dir).join(format!("{}.txt", args.file_name()));
+    if input::verify(&file_path).is_ok_and(|valid| valid == Some(false))
+        && !should_continue(
+            &format!(
+                "'{}' was changed since it was downloaded and will be replaced.",
+                file_path.display()
+            ),
+            args.yes,
+        )
+    {
+        exit(0);
+    }
+    let content = input::apply_trailing_newline(&input.content, args.trailing_newline());
+    if args.encrypt {
+        write_encrypted(&args, &file_path, content);
+    } else if let Err(err) = input::write(&file_path, content) {
+        eprintln!("Error: Failed to write '{}': {err}", file_path.display());
+        exit(1);
+    }
+    ignore_inputs(&args);
+
+    if let Some(html) = html {
+        save_puzzle(&html, &scraper.base_url(), &dir);
+    }
+}
+
+/// Writes `input.txt.enc` and removes the plain input, so that only the encrypted one is kept
+fn write_encrypted(args: &Args, file_path: &Path, content: &str) {
+    let secret = args.secret().unwrap_or_else(|| {
+        eprintln!("Error: --encrypt needs a passphrase in {PASSPHRASE_ENV_VAR} or --key-file.");
+        exit(1);
+    });
+    let encrypted = store::encrypted_path(file_path);
+    if let Err(err) = store::write_encrypted(file_path, content, &secret) {
+        eprintln!("Error: Failed to write '{}': {err}", encrypted.display());
+        exit(1);
+    }
+    for path in [file_path.to_path_buf(), input::checksum_path(file_path)] {
+        if let Err(err) = fs::remove_file(&path) {
+            if err.kind() != io::ErrorKind::NotFound {
+                eprintln!("Error: Failed to remove '{}': {err}", path.display());
                 exit(1);
             }
-        } else {
+        }
+    }
+}
+
+/// Keeps the plain inputs out of git with the `.gitignore` of the output root
+fn ignore_inputs(args: &Args) {
+    let patterns = [format!("{}.txt", args.file_name())];
+    if let Err(err) = gitignore::ensure_ignored(args.output_root(), &patterns) {
+        eprintln!(
+            "Error: Failed to update '{}': {err}",
+            gitignore::GITIGNORE_FILE_NAME
+        );
+        exit(1);
+    }
+}
+
+/// Adds the package to the workspace of the current directory. If there is none yet, it is
+/// created with only this package, the existing days are converted by `migrate`.
+fn register_in_workspace(root: &Path, package: &str) {
+    let result = match Workspace::open(root) {
+        Ok(Some(mut workspace)) => workspace.add_member(package).and_then(|_| workspace.save()),
+        Ok(None) => workspace::create(root, package).map(|workspace| {
+            println!("Created the workspace '{MANIFEST_FILE_NAME}' with '{package}'");
+            let days = workspace
+                .excluded()
+                .into_iter()
+                .filter(|dir| workspace::is_day_package(dir.rsplit('/').next().unwrap_or(dir)))
+                .count();
+            if days > 0 {
+                println!(
+                    "{days} existing days were left out, run `start_coding migrate` to add them"
+                );
+            }
+        }),
+        Err(err) => Err(err),
+    };
+    if let Err(err) = result {
+        eprintln!("Error: Failed to add '{package}' to the workspace: {err}");
+        exit(1);
+    }
+}
+
+fn fetch_all(args: &Args, year: i32, options: &FetchOptions) {
+    let session = args.session_provider().session().unwrap_or_else(|err| {
+        eprintln!("Error: {err}");
+        exit(1);
+    });
+    let cache = InputCache::open_default().unwrap_or_else(|| {
+        eprintln!("Error: The cache directory could not be determined, set XDG_CACHE_HOME.");
+        exit(1);
+    });
+
+    let days = bulk::unlocked_days(year);
+    if days.is_empty() {
+        eprintln!("Error: No puzzle of {year} is unlocked yet.");
+        exit(1);
+    }
+    println!("Downloading {} inputs of {year}...", days.len());
+    let statuses = bulk::fetch_all(year, &days, &session, &cache, options);
+
+    println!("| Day | Status  | Details");
+    println!("|-----|---------|--------");
+    for (day, status) in &statuses {
+        match status {
+            DayStatus::Failed(err) => println!("| {day:>3} | failed  | {err}"),
+            status => println!("| {day:>3} | {status:<7} |"),
+        }
+    }
+
+    let count = |f: fn(&DayStatus) -> bool| statuses.iter().filter(|(_, s)| f(s)).count();
+    let failed = count(|s| matches!(s, DayStatus::Failed(_)));
+    println!(
+        "{} fetched, {} cached, {failed} failed",
+        count(|s| matches!(s, DayStatus::Fetched)),
+        count(|s| matches!(s, DayStatus::Cached)),
+    );
+    if failed > 0 {
+        exit(1);
+    }
+}
+
+fn whoami(args: &Args) -> ! {
+    let scraper = AutoInputScraper::new(
+        calendar::latest_year(calendar::now()),
+        1,
+        args.session_provider(),
+    )
+    .with_fetcher(Box::new(ReqwestFetcher::new(&args.user_agent())))
+    .with_base_url(&args.base_url());
+
+    match scraper.whoami() {
+        Ok(account) => {
+            println!(
+                "Logged in as {} (session from the {})",
+                account.name, account.source
+            );
             exit(0);
         }
+        Err(ScrapeError::MissingSession(source)) => {
+            eprintln!("No session found, looked in: {source}.");
+        }
+        Err(ScrapeError::ExpiredSession) => {
+            let source = scraper.session_source().unwrap_or_default();
+            eprintln!("The session from the {source} has expired, log in to Advent of Code again.");
+        }
+        Err(err) => eprintln!("Error: {err}"),
     }
+    exit(1);
+}
 
-    let scraper = AutoInputScraper::new();
-    let alg_input = scraper.get_result(Some(day), args.browser);
+fn show_status(args: &Args, year: i32) {
+    let days = bulk::unlocked_days(year);
+    let scraper = AutoInputScraper::new(year, 1, args.session_provider())
+        .with_fetcher(Box::new(ReqwestFetcher::new(&args.user_agent())))
+        .with_base_url(&args.base_url());
+    let account = scraper.account_key().ok();
+    let cache = InputCache::open_default();
 
-    let dir = format!("{new_package}/");
-    fs::create_dir_all(&dir).unwrap();
-    let file_path = Path::new(&dir).join(format!("{}.txt", args.file_name));
-    let mut file = File::create(file_path).unwrap();
+    let stars = if args.offline {
+        None
+    } else {
+        match scraper.get_calendar() {
+            Ok(html) => Some(status::parse_calendar(&html)),
+            Err(err) => {
+                eprintln!("The stars are unknown, the calendar could not be downloaded: {err}");
+                None
+            }
+        }
+    };
+    let progress = status::scan(
+        args.output_root(),
+        year,
+        args.legacy_year,
+        &days,
+        &format!("{}.txt", args.file_name()),
+        cache.as_ref().zip(account.as_deref()),
+        stars.as_ref(),
+    );
 
-    file.write_all(alg_input.as_bytes()).unwrap();
+    let mark = |done: bool| if done { "yes" } else { "-" };
+    println!("Day  Package  Input   Tests  Stars  Answers");
+    for day in &progress {
+        let input = match (day.input, day.edited, day.cached) {
+            (true, true, _) => "edited",
+            (true, false, _) => "yes",
+            (false, _, true) => "cached",
+            (false, _, false) => "-",
+        };
+        let stars = day
+            .stars
+            .map_or(String::from("?"), |stars| "*".repeat(stars as usize));
+        let answers = day
+            .answers
+            .iter()
+            .map(|part| part.to_string())
+            .collect::<Vec<_>>()
+            .join(", ");
+        println!(
+            "{:>3}  {:<7}  {input:<6}  {:<5}  {stars:<5}  {}",
+            day.day,
+            mark(day.package.is_some()),
+            mark(day.tests),
+            if answers.is_empty() { "-" } else { &answers },
+        );
+    }
+    if let Some(stars) = stars {
+        println!("{} stars", stars.values().map(|&n| n as u32).sum::<u32>());
+    }
 }
 
-fn should_continue(extra: &str) -> bool {
+fn show_leaderboard(args: &Args, year: i32, id: u64, json: bool) {
+    let scraper = AutoInputScraper::new(year, 1, args.session_provider())
+        .with_fetcher(Box::new(ReqwestFetcher::new(&args.user_agent())))
+        .with_base_url(&args.base_url());
+    let account = scraper.account_key().unwrap_or_else(|err| {
+        eprintln!("Error: {err}");
+        exit(1);
+    });
+    let cache = LeaderboardCache::open_default().unwrap_or_else(|| {
+        eprintln!("Error: The cache directory could not be determined, set XDG_CACHE_HOME.");
+        exit(1);
+    });
+
+    let content = match cache.get(&account, year, id) {
+        Ok(Some(content)) => content,
+        _ => {
+            let content = scraper.get_leaderboard(id).unwrap_or_else(|err| {
+                eprintln!("Error: {err}");
+                exit(1);
+            });
+            if let Err(err) = cache.put(&account, year, id, &content) {
+                eprintln!("Failed to cache the leaderboard: {err}");
+            }
+            content
+        }
+    };
+
+    if json {
+        print!("{content}");
+        return;
+    }
+    match Leaderboard::parse(&content) {
+        Ok(leaderboard) => print!("{}", leaderboard.render(year)),
+        Err(err) => {
+            eprintln!("Error: The leaderboard {id} of {year} could not be read: {err}");
+            exit(1);
+        }
+    }
+}
+
+fn migrate_workspace(root: &Path) {
+    let workspace = workspace::migrate(root).unwrap_or_else(|err| {
+        eprintln!("Error: Failed to migrate the packages to a workspace: {err}");
+        exit(1);
+    });
+    println!(
+        "Migrated {} packages to the workspace '{MANIFEST_FILE_NAME}':",
+        workspace.members().len()
+    );
+    for member in workspace.members() {
+        println!("  {member}");
+    }
+}
+
+fn create_package(args: &Args, year: i32, day: u32, package: &str, html: Option<&str>) {
+    let template = match &args.template {
+        Some(dir) => Template::load(dir).unwrap_or_else(|err| {
+            eprintln!(
+                "Error: Failed to load the template '{}': {err}",
+                dir.display()
+            );
+            exit(1);
+        }),
+        None => Template::default(),
+    };
+    let context = TemplateContext {
+        year,
+        day,
+        title: html.and_then(puzzle::title).unwrap_or(format!("Day {day}")),
+        package: scaffold::package_name(year, package.rsplit('/').next().unwrap_or_default()),
+        dependencies: args.dependencies.clone(),
+        answers: html.map(puzzle::examples).unwrap_or_default().answers,
+    };
+    if let Err(err) = template.generate(Path::new(package), &context) {
+        eprintln!("Failed to create package '{package}': {err}");
+        exit(1);
+    }
+    println!("Successfully created package '{}'", package);
+}
+
+/// `YEAR/archive/dayN-TIMESTAMP` for the package directory `YEAR/dayN`
+fn archive_path(package_dir: &Path) -> PathBuf {
+    let name = package_dir.file_name().unwrap().to_string_lossy();
+    let timestamp = Local::now().format("%Y%m%d-%H%M%S");
+    package_dir
+        .parent()
+        .unwrap()
+        .join(ARCHIVE_DIR_NAME)
+        .join(format!("{name}-{timestamp}"))
+}
+
+fn fetch_puzzle(scraper: &AutoInputScraper) -> Option<String> {
+    match scraper.get_puzzle() {
+        Ok(html) => Some(html),
+        Err(err) => {
+            eprintln!("Failed to download the puzzle description: {err}");
+            None
+        }
+    }
+}
+
+/// Saves the puzzle description and its examples into the directory. The examples are always
+/// written, the tests generated in the package include them.
+fn save_puzzle(html: &str, base_url: &str, dir: &str) {
+    let examples = puzzle::examples(html);
+    for (idx, example) in examples.inputs.iter().enumerate() {
+        fs::write(Path::new(dir).join(Examples::file_name(idx + 1)), example)
+            .expect("Failed to write the example input.");
+    }
+
+    let markdown = puzzle::to_markdown(html, base_url);
+    if markdown.trim().is_empty() {
+        eprintln!("Puzzle description not found.");
+        return;
+    }
+    fs::write(Path::new(dir).join(puzzle::PUZZLE_FILE_NAME), markdown)
+        .expect("Failed to write the puzzle description.");
+}
+
+fn submit(scraper: &AutoInputScraper, part: u8, answer: &str, package: &str) -> ! {
+    let (year, day) = (scraper.year(), scraper.day());
+    let mut history = SubmissionHistory::load(Path::new(package)).unwrap_or_else(|err| {
+        eprintln!("Error: Failed to read the submission history: {err}");
+        exit(1);
+    });
+
+    if let Some(outcome) = history.predict(year, day, part, answer) {
+        println!("The answer was not submitted, based on the previous attempts: {outcome}");
+        exit(outcome.exit_code());
+    }
+
+    let outcome = scraper.submit_answer(part, answer).unwrap_or_else(|err| {
+        eprintln!("Error: {err}");
+        exit(1);
+    });
+    println!("{outcome}");
+    history.record(year, day, part, answer, outcome.clone());
+    if let Err(err) = history.save() {
+        eprintln!("Failed to save the submission history: {err}");
+    }
+
+    if outcome == SubmitOutcome::Correct && part == 1 && Path::new(package).exists() {
+        // The second part is unlocked now
+        if let Some(html) = fetch_puzzle(scraper) {
+            save_puzzle(&html, &scraper.base_url(), package);
+        }
+    }
+    exit(outcome.exit_code());
+}
+
+fn wait_for_unlock(year: i32, day: u32) {
+    while let Some(left) = calendar::time_until_unlock(year, day) {
+        print!(
+            "\rDay {day} of {year} unlocks in {}  ",
+            format_duration(left)
+        );
+        io::stdout().flush().unwrap();
+        thread::sleep(left.min(Duration::from_secs(1)));
+    }
+    println!("\rDay {day} of {year} is unlocked!{:20}", "");
+}
+
+fn format_duration(duration: Duration) -> String {
+    let secs = duration.as_secs();
+    let time = format!(
+        "{:02}:{:02}:{:02}",
+        secs / 3600 % 24,
+        secs / 60 % 60,
+        secs % 60
+    );
+    match secs / 86400 {
+        0 => time,
+        days => format!("{days}d {time}"),
+    }
+}
+
+fn should_continue(extra: &str, assume_yes: bool) -> bool {
+    if assume_yes {
+        return true;
+    }
     print!(
         "{}Do you want to continue? (y/N): ",
         if !extra.is_empty() {
@@ -115,8 +855,5 @@ fn should_continue(extra: &str) -> bool {
     io::stdin().read_line(&mut input).unwrap();
     let input = input.trim();
 
-    match input.to_lowercase().as_str() {
-        "y" | "Y" | "yes" | "Yes" => true,
-        _ => false,
-    }
+    matches!(input.to_lowercase().as_str(), "y" | "yes")
 }
@@ -0,0 +1,21 @@
+use std::env;
+use std::path::PathBuf;
+
+const APP_DIR_NAME: &str = "start_coding";
+
+fn xdg_dir(var: &str, fallback: &str) -> Option<PathBuf> {
+    env::var_os(var)
+        .filter(|dir| !dir.is_empty())
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| PathBuf::from(home).join(fallback)))
+}
+
+/// `$XDG_CONFIG_HOME/start_coding`, or `~/.config/start_coding`
+pub fn config_dir() -> Option<PathBuf> {
+    xdg_dir("XDG_CONFIG_HOME", ".config").map(|dir| dir.join(APP_DIR_NAME))
+}
+
+/// `$XDG_CACHE_HOME/start_coding`, or `~/.cache/start_coding`
+pub fn cache_dir() -> Option<PathBuf> {
+    xdg_dir("XDG_CACHE_HOME", ".cache").map(|dir| dir.join(APP_DIR_NAME))
+}
@@ -0,0 +1,332 @@
+//! Converts the puzzle page into Markdown. The puzzle descriptions only use a small set of
+//! tags (`h2`, `p`, `em`, `code`, `pre`, `ul`, `li`, `a` and `span`), so a simple tokenizer
+//! is enough to render them.
+
+pub const PUZZLE_FILE_NAME: &str = "PUZZLE.md";
+pub const EXAMPLE_FILE_PREFIX: &str = "example";
+
+const ARTICLE_START: &str = "<article class=\"day-desc\">";
+const ARTICLE_END: &str = "</article>";
+
+#[derive(Debug, PartialEq)]
+pub enum Token<'a> {
+    Open(&'a str, &'a str),
+    Close(&'a str),
+    Text(String),
+}
+
+/// Splits the HTML into opening tags (with their attributes), closing tags and decoded text
+pub fn tokenize(html: &str) -> Vec<Token<'_>> {
+    let mut tokens = vec![];
+    let mut rest = html;
+
+    while !rest.is_empty() {
+        if let Some(tag_start) = rest.strip_prefix('<') {
+            let Some(end) = tag_start.find('>') else {
+                tokens.push(Token::Text(decode_entities(rest)));
+                break;
+            };
+            let tag = tag_start[..end].trim_end_matches('/');
+            if let Some(name) = tag.strip_prefix('/') {
+                tokens.push(Token::Close(name.trim()));
+            } else {
+                let (name, attrs) = tag.split_once(' ').unwrap_or((tag, ""));
+                tokens.push(Token::Open(name, attrs));
+            }
+            rest = &tag_start[end + 1..];
+        } else {
+            let end = rest.find('<').unwrap_or(rest.len());
+            tokens.push(Token::Text(decode_entities(&rest[..end])));
+            rest = &rest[end..];
+        }
+    }
+    tokens
+}
+
+fn decode_entities(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&apos;", "'")
+        .replace("&nbsp;", " ")
+        .replace("&amp;", "&")
+}
+
+pub(crate) fn get_attr<'a>(attrs: &'a str, name: &str) -> Option<&'a str> {
+    let start = attrs.find(&format!("{name}=\""))? + name.len() + 2;
+    let end = start + attrs[start..].find('"')?;
+    Some(&attrs[start..end])
+}
+
+/// The `<article class="day-desc">` sections of the puzzle page, one for each unlocked part
+pub fn articles(html: &str) -> Vec<&str> {
+    let mut result = vec![];
+    let mut rest = html;
+    while let Some(start) = rest.find(ARTICLE_START) {
+        let content = &rest[start + ARTICLE_START.len()..];
+        let end = content.find(ARTICLE_END).unwrap_or(content.len());
+        result.push(&content[..end]);
+        rest = &content[end..];
+    }
+    result
+}
+
+/// Title of the puzzle from `<h2>--- Day 1: Historian Hysteria ---</h2>`
+pub fn title(html: &str) -> Option<String> {
+    let article = articles(html).into_iter().next()?;
+    let start = article.find("<h2>")? + "<h2>".len();
+    let end = start + article[start..].find("</h2>")?;
+    let heading = decode_entities(&article[start..end]);
+    let heading = heading.trim_matches(['-', ' ']);
+    Some(
+        heading
+            .split_once(": ")
+            .map_or(heading, |(_, title)| title)
+            .to_owned(),
+    )
+}
+
+/// Renders all unlocked parts of the puzzle page as Markdown
+pub fn to_markdown(html: &str, base_url: &str) -> String {
+    articles(html)
+        .iter()
+        .map(|article| article_to_markdown(article, base_url))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn article_to_markdown(article: &str, base_url: &str) -> String {
+    let mut result = String::new();
+    let mut in_h2 = false;
+    let mut in_pre = false;
+    let mut in_code = false;
+    let mut em_in_code = false;
+    let mut links: Vec<String> = vec![];
+
+    for token in tokenize(article) {
+        match token {
+            Token::Open("h2", _) => {
+                in_h2 = true;
+                result.push_str("## ");
+            }
+            Token::Close("h2") => {
+                in_h2 = false;
+                result.push_str("\n\n");
+            }
+            Token::Close("p") => result.push_str("\n\n"),
+            Token::Open("pre", _) => {
+                in_pre = true;
+                result.push_str("```\n");
+            }
+            Token::Close("pre") => {
+                in_pre = false;
+                if !result.ends_with('\n') {
+                    result.push('\n');
+                }
+                result.push_str("```\n\n");
+            }
+            Token::Open("code", _) if !in_pre => {
+                in_code = true;
+                result.push('`');
+            }
+            Token::Close("code") if !in_pre => {
+                in_code = false;
+                result.push('`');
+                if em_in_code {
+                    em_in_code = false;
+                    result.push('*');
+                }
+            }
+            // Markdown can't emphasize inside inline code, so `<code><em>` wraps the whole code
+            Token::Open("em", _) if in_code && result.ends_with('`') => {
+                em_in_code = true;
+                result.insert(result.len() - 1, '*');
+            }
+            Token::Open("em", _) | Token::Close("em") if !in_pre && !in_code => result.push('*'),
+            Token::Open("li", _) => result.push_str("- "),
+            Token::Close("li") => result.push('\n'),
+            Token::Close("ul") => result.push('\n'),
+            Token::Open("a", attrs) => {
+                let href = get_attr(attrs, "href").unwrap_or_default();
+                links.push(if href.starts_with('/') {
+                    format!("{base_url}{href}")
+                } else {
+                    href.to_owned()
+                });
+                result.push('[');
+            }
+            Token::Close("a") => {
+                result.push_str(&format!("]({})", links.pop().unwrap_or_default()));
+            }
+            Token::Text(text) if in_pre => result.push_str(&text),
+            Token::Text(text) if in_h2 => result.push_str(text.trim_matches(['-', ' '])),
+            Token::Text(text) => result.push_str(&text.replace('\n', " ")),
+            _ => {}
+        }
+    }
+    result.trim_end().to_owned() + "\n"
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct ExampleAnswer {
+    pub part: u8,
+    /// Number of the example the answer belongs to, starting from 1
+    pub example: usize,
+    pub answer: String,
+}
+
+#[derive(Debug, PartialEq, Default)]
+pub struct Examples {
+    pub inputs: Vec<String>,
+    pub answers: Vec<ExampleAnswer>,
+}
+
+impl Examples {
+    pub fn file_name(example: usize) -> String {
+        format!("{EXAMPLE_FILE_PREFIX}{example}.txt")
+    }
+}
+
+/// Collects the `<pre><code>` blocks of the puzzle page and the example answer of each part.
+/// The answer is the last emphasized code in the part and it belongs to the first example
+/// of the part, or to the first example of the puzzle if the part has none.
+pub fn examples(html: &str) -> Examples {
+    let mut examples = Examples::default();
+
+    for (part, article) in articles(html).iter().enumerate() {
+        let first_in_part = examples.inputs.len();
+        let mut in_pre = false;
+        let mut in_code = false;
+        let mut in_em = false;
+        let mut text = String::new();
+        let mut answer: Option<String> = None;
+
+        for token in tokenize(article) {
+            match token {
+                Token::Open("pre", _) => {
+                    in_pre = true;
+                    text.clear();
+                }
+                Token::Close("pre") => {
+                    in_pre = false;
+                    examples.inputs.push(text.clone());
+                }
+                Token::Open("code", _) => in_code = true,
+                Token::Close("code") => in_code = false,
+                Token::Open("em", _) if in_code && !in_pre => {
+                    in_em = true;
+                    answer = Some(String::new());
+                }
+                Token::Close("em") => in_em = false,
+                Token::Text(t) if in_pre => text.push_str(&t),
+                Token::Text(t) if in_em => {
+                    if let Some(answer) = answer.as_mut() {
+                        answer.push_str(&t);
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        if let Some(answer) = answer {
+            let example = if examples.inputs.len() > first_in_part {
+                first_in_part + 1
+            } else {
+                1
+            };
+            if example <= examples.inputs.len() {
+                examples.answers.push(ExampleAnswer {
+                    part: part as u8 + 1,
+                    example,
+                    answer,
+                });
+            }
+        }
+    }
+    examples
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tokenize() {
+        assert_eq!(
+            tokenize("<p class=\"x\">a &lt; b</p><br/>"),
+            vec![
+                Token::Open("p", "class=\"x\""),
+                Token::Text(String::from("a < b")),
+                Token::Close("p"),
+                Token::Open("br", ""),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_to_markdown() {
+        let html = "<main><article class=\"day-desc\"><h2>--- Day 1: Historian Hysteria ---</h2>\
+<p>The <em>Chief Historian</em> is missing, see <a href=\"/2024/about\">about</a>.</p>\
+<pre><code>3   4\n<em>4</em>   3\n</code></pre>\
+<ul><li>Pair up <code>1</code>.</li><li>The total is <code><em>11</em></code>.</li></ul>\
+</article><p>Answer: <input/></p>\
+<article class=\"day-desc\"><h2 id=\"part2\">--- Part Two ---</h2><p>Again.</p></article></main>";
+
+        assert_eq!(
+            to_markdown(html, "https://adventofcode.com"),
+            "## Day 1: Historian Hysteria
+
+The *Chief Historian* is missing, see [about](https://adventofcode.com/2024/about).
+
+```
+3   4
+4   3
+```
+
+- Pair up `1`.
+- The total is *`11`*.
+
+## Part Two
+
+Again.
+"
+        );
+    }
+
+    #[test]
+    fn test_title() {
+        let html = "<article class=\"day-desc\"><h2>--- Day 4: Ceres Search ---</h2></article>";
+        assert_eq!(title(html), Some(String::from("Ceres Search")));
+        assert_eq!(title("<main></main>"), None);
+    }
+
+    #[test]
+    fn test_examples() {
+        let html = "<article class=\"day-desc\"><h2>--- Day 1: Historian Hysteria ---</h2>\
+<pre><code>3   4\n4   3\n</code></pre>\
+<p>The pairs are:</p><pre><code><em>3</em>   4\n</code></pre>\
+<p>In the example, the total is <code>1</code> + <code><em>11</em></code>.</p></article>\
+<article class=\"day-desc\"><h2 id=\"part2\">--- Part Two ---</h2>\
+<p>So, the similarity score is <code><em>31</em></code>.</p></article>";
+
+        assert_eq!(
+            examples(html),
+            Examples {
+                inputs: vec![String::from("3   4\n4   3\n"), String::from("3   4\n")],
+                answers: vec![
+                    ExampleAnswer {
+                        part: 1,
+                        example: 1,
+                        answer: String::from("11")
+                    },
+                    ExampleAnswer {
+                        part: 2,
+                        example: 1,
+                        answer: String::from("31")
+                    },
+                ],
+            }
+        );
+    }
+}
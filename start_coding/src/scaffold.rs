@@ -0,0 +1,240 @@
+//! Generates the day package from a template. Every file of the template is rendered with
+//! these placeholders:
+//!
+//! - `{{year}}`, `{{day}}` and `{{title}}` of the puzzle
+//! - `{{package}}` and `{{crate}}`, the package name (`aoc2024-day1`) and its crate name used in
+//!   the code (`aoc2024_day1`)
+//! - `{{dependencies}}`, the `[dependencies]` lines of `Cargo.toml`
+//! - `{{tests}}`, a `#[cfg(test)]` module asserting the example answers
+use crate::puzzle::{ExampleAnswer, Examples};
+use serde::Deserialize;
+use std::fmt;
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+const CARGO_TOML: &str = r#"[package]
+name = "{{package}}"
+version = "0.1.0"
+edition = "2021"
+
+[dependencies]
+{{dependencies}}
+"#;
+
+const LIB_RS: &str = r#"//! {{title}}
+//!
+//! <https://adventofcode.com/{{year}}/day/{{day}}>
+
+pub fn part1(input: &str) -> usize {
+    todo!("{}", input.len())
+}
+
+pub fn part2(input: &str) -> usize {
+    todo!("{}", input.len())
+}
+{{tests}}"#;
+
+const MAIN_RS: &str = r#"use std::io;
+use std::io::Read;
+use {{crate}}::{part1, part2};
+
+fn main() {
+    let mut input = String::new();
+    io::stdin().read_to_string(&mut input).expect("Failed to read from stdin");
+
+    println!("Part 1: {}", part1(&input));
+    println!("Part 2: {}", part2(&input));
+}
+"#;
+
+pub const DEFAULT_DEPENDENCIES: [&str; 1] = ["text_io=0.1.12"];
+
+/// Name of the package in the `YEAR/dir` directory, the year keeps the days of different years
+/// apart in one workspace
+pub fn package_name(year: i32, dir: &str) -> String {
+    format!("aoc{year}-{dir}")
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(try_from = "String")]
+pub struct Dependency {
+    pub name: String,
+    pub version: String,
+}
+
+impl FromStr for Dependency {
+    type Err = String;
+
+    /// Parses `NAME=VERSION`, or just `NAME` for any version
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (name, version) = s.split_once('=').unwrap_or((s, "*"));
+        let (name, version) = (name.trim(), version.trim());
+        if name.is_empty() || version.is_empty() {
+            return Err(format!("Invalid dependency '{s}', expected NAME=VERSION"));
+        }
+        Ok(Self {
+            name: name.to_owned(),
+            version: version.to_owned(),
+        })
+    }
+}
+
+impl TryFrom<String> for Dependency {
+    type Error = String;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl Display for Dependency {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{} = \"{}\"", self.name, self.version)
+    }
+}
+
+/// Values of the placeholders
+pub struct TemplateContext {
+    pub year: i32,
+    pub day: u32,
+    pub title: String,
+    pub package: String,
+    pub dependencies: Vec<Dependency>,
+    pub answers: Vec<ExampleAnswer>,
+}
+
+impl TemplateContext {
+    pub fn render(&self, template: &str) -> String {
+        let dependencies: Vec<String> = self.dependencies.iter().map(|d| d.to_string()).collect();
+        template
+            .replace("{{year}}", &self.year.to_string())
+            .replace("{{day}}", &self.day.to_string())
+            .replace("{{title}}", &self.title)
+            .replace("{{package}}", &self.package)
+            .replace("{{crate}}", &self.package.replace('-', "_"))
+            .replace("{{dependencies}}", &dependencies.join("\n"))
+            .replace("{{tests}}", &tests_module(&self.answers))
+    }
+}
+
+/// `#[cfg(test)]` module with a test for each example answer
+fn tests_module(answers: &[ExampleAnswer]) -> String {
+    if answers.is_empty() {
+        return String::new();
+    }
+
+    let mut result = String::from("\n#[cfg(test)]\nmod tests {\n    use super::*;\n");
+    for answer in answers {
+        result.push_str(&format!(
+            r#"
+    #[test]
+    fn test_part{part}_example() {{
+        let input = include_str!("../{file}");
+        assert_eq!(part{part}(input).to_string(), "{answer}");
+    }}
+"#,
+            part = answer.part,
+            file = Examples::file_name(answer.example),
+            answer = answer.answer.escape_default(),
+        ));
+    }
+    result.push_str("}\n");
+    result
+}
+
+/// Files of the package, with paths relative to the package directory
+pub struct Template {
+    files: Vec<(PathBuf, String)>,
+}
+
+impl Default for Template {
+    fn default() -> Self {
+        Self {
+            files: vec![
+                (PathBuf::from("Cargo.toml"), String::from(CARGO_TOML)),
+                (PathBuf::from("src/lib.rs"), String::from(LIB_RS)),
+                (PathBuf::from("src/main.rs"), String::from(MAIN_RS)),
+            ],
+        }
+    }
+}
+
+impl Template {
+    /// Loads every file of the template directory
+    pub fn load(dir: &Path) -> io::Result<Self> {
+        let mut files = vec![];
+        let mut dirs = vec![dir.to_path_buf()];
+        while let Some(current) = dirs.pop() {
+            for entry in fs::read_dir(current)? {
+                let path = entry?.path();
+                if path.is_dir() {
+                    dirs.push(path);
+                } else {
+                    let relative = path.strip_prefix(dir).unwrap().to_path_buf();
+                    files.push((relative, fs::read_to_string(&path)?));
+                }
+            }
+        }
+        files.sort();
+        Ok(Self { files })
+    }
+
+    pub fn generate(&self, package_dir: &Path, context: &TemplateContext) -> io::Result<()> {
+        for (path, content) in &self.files {
+            let path = package_dir.join(path);
+            if let Some(dir) = path.parent() {
+                fs::create_dir_all(dir)?;
+            }
+            fs::write(path, context.render(content))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_dependency() {
+        assert_eq!(
+            "regex=1.11.1".parse(),
+            Ok(Dependency {
+                name: String::from("regex"),
+                version: String::from("1.11.1")
+            })
+        );
+        assert_eq!(
+            "itertools".parse::<Dependency>().unwrap().to_string(),
+            "itertools = \"*\""
+        );
+        assert!("=1".parse::<Dependency>().is_err());
+    }
+
+    #[test]
+    fn test_render() {
+        let context = TemplateContext {
+            year: 2024,
+            day: 1,
+            title: String::from("Historian Hysteria"),
+            package: package_name(2024, "test_day1"),
+            dependencies: vec!["text_io=0.1.12".parse().unwrap()],
+            answers: vec![ExampleAnswer {
+                part: 1,
+                example: 1,
+                answer: String::from("11"),
+            }],
+        };
+
+        assert_eq!(
+            context.render("// {{year}} {{day}} {{title}}\nuse {{crate}};\n{{dependencies}}"),
+            "// 2024 1 Historian Hysteria\nuse aoc2024_test_day1;\ntext_io = \"0.1.12\""
+        );
+        assert!(context
+            .render("{{tests}}")
+            .contains("assert_eq!(part1(input).to_string(), \"11\");"));
+    }
+}
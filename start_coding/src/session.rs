@@ -0,0 +1,263 @@
+use crate::error::ScrapeError;
+use crate::{paths, Browsers, ADVENT_OF_CODE_WEBSITE};
+use clap::ValueEnum;
+#[cfg(target_os = "macos")]
+use rookie::safari;
+use rookie::{brave, chrome, firefox};
+use serde::Deserialize;
+use std::fmt;
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::path::PathBuf;
+
+pub const SESSION_ENV_VAR: &str = "AOC_SESSION";
+pub const SESSION_FILE_NAME: &str = "session";
+pub const COOKIES_FILE_NAME: &str = "cookies.txt";
+
+/// The Advent of Code session token and a description of where it was found
+#[derive(Debug, Clone, PartialEq)]
+pub struct Session {
+    pub token: String,
+    pub source: String,
+}
+
+pub trait SessionProvider {
+    /// Where the provider looks for the session, used in the messages
+    fn describe(&self) -> String;
+
+    fn session(&self) -> Result<Session, ScrapeError>;
+
+    fn found(&self, token: &str) -> Result<Session, ScrapeError> {
+        let token = token.trim();
+        let token = token.strip_prefix("session=").unwrap_or(token);
+        if token.is_empty() {
+            return Err(ScrapeError::MissingSession(self.describe()));
+        }
+        Ok(Session {
+            token: token.to_owned(),
+            source: self.describe(),
+        })
+    }
+}
+
+/// A session that was already found, shared without looking it up again
+impl SessionProvider for Session {
+    fn describe(&self) -> String {
+        self.source.clone()
+    }
+
+    fn session(&self) -> Result<Session, ScrapeError> {
+        Ok(self.clone())
+    }
+}
+
+/// Reads the session from the `AOC_SESSION` environment variable
+pub struct EnvProvider;
+
+impl SessionProvider for EnvProvider {
+    fn describe(&self) -> String {
+        format!("{SESSION_ENV_VAR} environment variable")
+    }
+
+    fn session(&self) -> Result<Session, ScrapeError> {
+        let token = std::env::var(SESSION_ENV_VAR)
+            .map_err(|_| ScrapeError::MissingSession(self.describe()))?;
+        self.found(&token)
+    }
+}
+
+/// Reads the session token from a file, by default `~/.config/start_coding/session`
+pub struct TokenFileProvider {
+    pub path: Option<PathBuf>,
+}
+
+impl Default for TokenFileProvider {
+    fn default() -> Self {
+        Self {
+            path: paths::config_dir().map(|dir| dir.join(SESSION_FILE_NAME)),
+        }
+    }
+}
+
+impl SessionProvider for TokenFileProvider {
+    fn describe(&self) -> String {
+        match &self.path {
+            Some(path) => format!("token file {}", path.display()),
+            None => String::from("token file"),
+        }
+    }
+
+    fn session(&self) -> Result<Session, ScrapeError> {
+        let path = self
+            .path
+            .as_ref()
+            .ok_or(ScrapeError::MissingSession(self.describe()))?;
+        let token =
+            fs::read_to_string(path).map_err(|_| ScrapeError::MissingSession(self.describe()))?;
+        self.found(&token)
+    }
+}
+
+/// Imports the session from a cookies file in the Netscape `cookies.txt` format
+pub struct CookiesTxtProvider {
+    pub path: PathBuf,
+}
+
+impl CookiesTxtProvider {
+    fn find_session(content: &str) -> Option<&str> {
+        content.lines().find_map(|line| {
+            let line = line.strip_prefix("#HttpOnly_").unwrap_or(line);
+            if line.starts_with('#') {
+                return None;
+            }
+            let fields: Vec<&str> = line.split('\t').collect();
+            match fields[..] {
+                [domain, _, _, _, _, "session", value]
+                    if domain.trim_start_matches('.') == ADVENT_OF_CODE_WEBSITE =>
+                {
+                    Some(value)
+                }
+                _ => None,
+            }
+        })
+    }
+}
+
+impl SessionProvider for CookiesTxtProvider {
+    fn describe(&self) -> String {
+        format!("cookies file {}", self.path.display())
+    }
+
+    fn session(&self) -> Result<Session, ScrapeError> {
+        let content = fs::read_to_string(&self.path)
+            .map_err(|_| ScrapeError::MissingSession(self.describe()))?;
+        let token =
+            Self::find_session(&content).ok_or(ScrapeError::MissingSession(self.describe()))?;
+        self.found(token)
+    }
+}
+
+impl SessionProvider for Browsers {
+    fn describe(&self) -> String {
+        format!("{self} browser")
+    }
+
+    fn session(&self) -> Result<Session, ScrapeError> {
+        let domains = vec![String::from(ADVENT_OF_CODE_WEBSITE)];
+        let cookies = match self {
+            #[cfg(target_os = "macos")]
+            Browsers::Safari => safari(Some(domains)),
+            Browsers::Brave => brave(Some(domains)),
+            Browsers::Chrome => chrome(Some(domains)),
+            Browsers::Firefox => firefox(Some(domains)),
+        }
+        .map_err(|_| ScrapeError::MissingSession(self.describe()))?;
+
+        let cookie = cookies
+            .iter()
+            .find(|cookie| cookie.name == "session")
+            .ok_or(ScrapeError::MissingSession(self.describe()))?;
+        self.found(&cookie.value)
+    }
+}
+
+/// Tries the providers in order and returns the first session found
+pub struct FallbackProvider {
+    pub providers: Vec<Box<dyn SessionProvider>>,
+}
+
+impl SessionProvider for FallbackProvider {
+    fn describe(&self) -> String {
+        self.providers
+            .iter()
+            .map(|provider| provider.describe())
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
+    fn session(&self) -> Result<Session, ScrapeError> {
+        self.providers
+            .iter()
+            .find_map(|provider| provider.session().ok())
+            .ok_or(ScrapeError::MissingSession(self.describe()))
+    }
+}
+
+#[derive(ValueEnum, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+pub enum SessionSource {
+    /// Tries the environment variable, the token file, the cookies file and the browser in this order
+    Auto,
+    Env,
+    File,
+    CookiesTxt,
+    Browser,
+}
+
+impl Display for SessionSource {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            SessionSource::Auto => write!(f, "auto"),
+            SessionSource::Env => write!(f, "env"),
+            SessionSource::File => write!(f, "file"),
+            SessionSource::CookiesTxt => write!(f, "cookies-txt"),
+            SessionSource::Browser => write!(f, "browser"),
+        }
+    }
+}
+
+impl SessionSource {
+    pub fn provider(self, browser: Browsers, cookies_file: PathBuf) -> Box<dyn SessionProvider> {
+        match self {
+            SessionSource::Auto => Box::new(FallbackProvider {
+                providers: vec![
+                    Box::new(EnvProvider),
+                    Box::new(TokenFileProvider::default()),
+                    Box::new(CookiesTxtProvider { path: cookies_file }),
+                    Box::new(browser),
+                ],
+            }),
+            SessionSource::Env => Box::new(EnvProvider),
+            SessionSource::File => Box::new(TokenFileProvider::default()),
+            SessionSource::CookiesTxt => Box::new(CookiesTxtProvider { path: cookies_file }),
+            SessionSource::Browser => Box::new(browser),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cookies_txt() {
+        let content = "# Netscape HTTP Cookie File
+.example.com\tTRUE\t/\tFALSE\t0\tsession\tnope
+#HttpOnly_.adventofcode.com\tTRUE\t/\tTRUE\t1767225600\tsession\t53616c7465
+";
+        assert_eq!(
+            CookiesTxtProvider::find_session(content),
+            Some("53616c7465")
+        );
+        assert_eq!(
+            CookiesTxtProvider::find_session("# Netscape HTTP Cookie File"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_fallback_order() {
+        let provider = FallbackProvider {
+            providers: vec![
+                Box::new(TokenFileProvider { path: None }),
+                Box::new(CookiesTxtProvider {
+                    path: PathBuf::from("/nonexistent/cookies.txt"),
+                }),
+            ],
+        };
+        assert!(matches!(
+            provider.session(),
+            Err(ScrapeError::MissingSession(_))
+        ));
+    }
+}
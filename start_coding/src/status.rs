@@ -0,0 +1,183 @@
+//! Progress of the days of an event, gathered from the day packages, the input cache and the
+//! calendar page of Advent of Code.
+use crate::cache::InputCache;
+use crate::history::{SubmissionHistory, HISTORY_FILE_NAME};
+use crate::input;
+use crate::puzzle::{get_attr, tokenize, Token};
+use crate::submit::SubmitOutcome;
+use puzzle_input::{find_package, store};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct DayProgress {
+    pub day: u32,
+    pub package: Option<PathBuf>,
+    /// The input file is in the package, plain or encrypted
+    pub input: bool,
+    /// The input file doesn't match its checksum anymore
+    pub edited: bool,
+    pub cached: bool,
+    pub tests: bool,
+    /// `None` if the calendar could not be read
+    pub stars: Option<u8>,
+    /// Parts with a correct answer in the submission history
+    pub answers: Vec<u8>,
+}
+
+/// Stars of each day from the links of the calendar page, `calendar-complete` for one star
+/// and `calendar-verycomplete` for two
+pub fn parse_calendar(html: &str) -> BTreeMap<u32, u8> {
+    let mut stars = BTreeMap::new();
+    for token in tokenize(html) {
+        let Token::Open("a", attrs) = token else {
+            continue;
+        };
+        let classes: Vec<&str> = get_attr(attrs, "class")
+            .unwrap_or_default()
+            .split_whitespace()
+            .collect();
+        let Some(day) = classes
+            .iter()
+            .find_map(|class| class.strip_prefix("calendar-day")?.parse().ok())
+        else {
+            continue;
+        };
+        let count = if classes.contains(&"calendar-verycomplete") {
+            2
+        } else if classes.contains(&"calendar-complete") {
+            1
+        } else {
+            0
+        };
+        stars.insert(day, count);
+    }
+    stars
+}
+
+/// Looks for a `#[test]` in the sources and the `tests/` directory of the package
+pub fn has_tests(package: &Path) -> bool {
+    let mut dirs = vec![package.join("src"), package.join("tests")];
+    while let Some(dir) = dirs.pop() {
+        let Ok(entries) = fs::read_dir(&dir) else {
+            continue;
+        };
+        for path in entries.flatten().map(|entry| entry.path()) {
+            if path.is_dir() {
+                dirs.push(path);
+            } else if path.extension().is_some_and(|ext| ext == "rs")
+                && fs::read_to_string(&path).is_ok_and(|code| code.contains("#[test]"))
+            {
+                return true;
+            }
+        }
+    }
+    false
+}
+
+/// Progress of the days, `cache` is the input cache with the key of the account
+pub fn scan(
+    root: &Path,
+    year: i32,
+    legacy_year: Option<i32>,
+    days: &[u32],
+    input_file: &str,
+    cache: Option<(&InputCache, &str)>,
+    stars: Option<&BTreeMap<u32, u8>>,
+) -> Vec<DayProgress> {
+    days.iter()
+        .map(|&day| {
+            let package = find_package(root, year, day, legacy_year);
+            let answers = package
+                .as_ref()
+                .filter(|dir| dir.join(HISTORY_FILE_NAME).is_file())
+                .and_then(|dir| SubmissionHistory::load(dir).ok())
+                .map(|history| {
+                    (1..=2)
+                        .filter(|&part| {
+                            history
+                                .attempts(year, day, part)
+                                .any(|attempt| attempt.outcome == SubmitOutcome::Correct)
+                        })
+                        .collect()
+                })
+                .unwrap_or_default();
+
+            DayProgress {
+                day,
+                input: package.as_ref().is_some_and(|dir| {
+                    let path = dir.join(input_file);
+                    path.is_file() || store::encrypted_path(&path).is_file()
+                }),
+                edited: package.as_ref().is_some_and(|dir| {
+                    input::verify(&dir.join(input_file)).is_ok_and(|valid| valid == Some(false))
+                }),
+                cached: cache.is_some_and(|(cache, account)| cache.contains(account, year, day)),
+                tests: package.as_deref().is_some_and(has_tests),
+                stars: stars.map(|stars| stars.get(&day).copied().unwrap_or_default()),
+                answers,
+                package,
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use puzzle_input::MANIFEST_FILE_NAME;
+
+    #[test]
+    fn test_parse_calendar() {
+        let html = r#"<pre class="calendar">
+<a aria-label="Day 1, two stars" href="/2024/day/1" class="calendar-day1 calendar-verycomplete">
+<a aria-label="Day 2, one star" href="/2024/day/2" class="calendar-day2 calendar-complete">
+<a aria-label="Day 3" href="/2024/day/3" class="calendar-day3">
+<span class="calendar-day4">"#;
+        assert_eq!(
+            parse_calendar(html),
+            BTreeMap::from([(1, 2), (2, 1), (3, 0)])
+        );
+    }
+
+    #[test]
+    fn test_scan() {
+        let root = std::env::temp_dir().join(format!("start_coding_status_{}", std::process::id()));
+        let package = root.join("2024").join("day2");
+        fs::create_dir_all(package.join("src")).unwrap();
+        fs::write(package.join(MANIFEST_FILE_NAME), "").unwrap();
+        fs::write(package.join("input.txt"), "1 2").unwrap();
+        fs::write(package.join("src").join("lib.rs"), "#[test]\nfn test() {}").unwrap();
+        let mut history = SubmissionHistory::load(&package).unwrap();
+        history.record(2024, 2, 1, "2", SubmitOutcome::Correct);
+        history.record(2024, 2, 2, "5", SubmitOutcome::TooLow);
+        history.save().unwrap();
+
+        let stars = BTreeMap::from([(1, 2), (2, 1)]);
+        let progress = scan(&root, 2024, None, &[1, 2], "input.txt", None, Some(&stars));
+        assert_eq!(
+            progress[0],
+            DayProgress {
+                day: 1,
+                stars: Some(2),
+                ..DayProgress::default()
+            }
+        );
+        assert_eq!(
+            progress[1],
+            DayProgress {
+                day: 2,
+                package: Some(package),
+                input: true,
+                edited: false,
+                cached: false,
+                tests: true,
+                stars: Some(1),
+                answers: vec![1],
+            }
+        );
+
+        fs::remove_dir_all(root).unwrap();
+    }
+}
@@ -0,0 +1,167 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::fmt::{Display, Formatter};
+use std::time::Duration;
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone)]
+pub enum SubmitOutcome {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+    RateLimited(Option<Duration>),
+    AlreadySolved,
+}
+
+impl SubmitOutcome {
+    /// Parses the page returned by `/{year}/day/{day}/answer`
+    pub fn from_html(html: &str) -> Option<Self> {
+        let text = article_text(html);
+
+        if text.contains("That's the right answer") {
+            Some(SubmitOutcome::Correct)
+        } else if text.contains("your answer is too high") {
+            Some(SubmitOutcome::TooHigh)
+        } else if text.contains("your answer is too low") {
+            Some(SubmitOutcome::TooLow)
+        } else if text.contains("That's not the right answer") {
+            Some(SubmitOutcome::Wrong)
+        } else if text.contains("You gave an answer too recently") {
+            Some(SubmitOutcome::RateLimited(parse_wait_time(&text)))
+        } else if text.contains("Did you already complete it?") {
+            Some(SubmitOutcome::AlreadySolved)
+        } else {
+            None
+        }
+    }
+
+    /// Exit status of the binary for each outcome, so scripts can react to it
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            SubmitOutcome::Correct => 0,
+            SubmitOutcome::TooHigh => 10,
+            SubmitOutcome::TooLow => 11,
+            SubmitOutcome::Wrong => 12,
+            SubmitOutcome::RateLimited(_) => 13,
+            SubmitOutcome::AlreadySolved => 14,
+        }
+    }
+}
+
+impl Display for SubmitOutcome {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            SubmitOutcome::Correct => write!(f, "That's the right answer!"),
+            SubmitOutcome::TooHigh => write!(f, "That's not the right answer, it is too high."),
+            SubmitOutcome::TooLow => write!(f, "That's not the right answer, it is too low."),
+            SubmitOutcome::Wrong => write!(f, "That's not the right answer."),
+            SubmitOutcome::RateLimited(Some(wait)) => write!(
+                f,
+                "You gave an answer too recently, wait {}s before trying again.",
+                wait.as_secs()
+            ),
+            SubmitOutcome::RateLimited(None) => {
+                write!(
+                    f,
+                    "You gave an answer too recently, wait before trying again."
+                )
+            }
+            SubmitOutcome::AlreadySolved => write!(f, "This part is already solved."),
+        }
+    }
+}
+
+/// Text of the `<article>` element with all the tags removed
+fn article_text(html: &str) -> String {
+    let article = match (html.find("<article"), html.find("</article>")) {
+        (Some(start), Some(end)) if start < end => &html[start..end],
+        _ => html,
+    };
+
+    let mut result = String::new();
+    let mut in_tag = false;
+    for c in article.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            _ if !in_tag => result.push(c),
+            _ => {}
+        }
+    }
+    result
+}
+
+/// Parses "You have 1m 5s left to wait."
+fn parse_wait_time(text: &str) -> Option<Duration> {
+    let start = text.find("You have ")? + "You have ".len();
+    let end = start + text[start..].find(" left to wait")?;
+
+    let mut secs = 0;
+    for part in text[start..end].split_whitespace() {
+        let (num, unit) = part.split_at(part.len() - 1);
+        let num: u64 = num.parse().ok()?;
+        secs += match unit {
+            "h" => num * 3600,
+            "m" => num * 60,
+            "s" => num,
+            _ => return None,
+        };
+    }
+    Some(Duration::from_secs(secs))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn page(message: &str) -> String {
+        format!("<html><body><main><article><p>{message}</p></article></main></body></html>")
+    }
+
+    #[test]
+    fn test_parse_outcomes() {
+        assert_eq!(
+            SubmitOutcome::from_html(&page(
+                "That's the right answer!  You are <em>one gold star</em> closer."
+            )),
+            Some(SubmitOutcome::Correct)
+        );
+        assert_eq!(
+            SubmitOutcome::from_html(&page(
+                "That's not the right answer; your answer is too high."
+            )),
+            Some(SubmitOutcome::TooHigh)
+        );
+        assert_eq!(
+            SubmitOutcome::from_html(&page(
+                "That's not the right answer; your answer is too low."
+            )),
+            Some(SubmitOutcome::TooLow)
+        );
+        assert_eq!(
+            SubmitOutcome::from_html(&page("That's not the right answer.  If you're stuck, ...")),
+            Some(SubmitOutcome::Wrong)
+        );
+        assert_eq!(
+            SubmitOutcome::from_html(&page(
+                "You don't seem to be solving the right level.  Did you already complete it?"
+            )),
+            Some(SubmitOutcome::AlreadySolved)
+        );
+        assert_eq!(SubmitOutcome::from_html("<html></html>"), None);
+    }
+
+    #[test]
+    fn test_parse_rate_limit() {
+        assert_eq!(
+            SubmitOutcome::from_html(&page("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 34s left to wait.")),
+            Some(SubmitOutcome::RateLimited(Some(Duration::from_secs(34))))
+        );
+        assert_eq!(
+            SubmitOutcome::from_html(&page(
+                "You gave an answer too recently.  You have 4m 2s left to wait."
+            )),
+            Some(SubmitOutcome::RateLimited(Some(Duration::from_secs(242))))
+        );
+    }
+}
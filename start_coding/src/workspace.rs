@@ -0,0 +1,478 @@
+//! Keeps the day packages in one Cargo workspace, so they share the `Cargo.lock`, the `target/`
+//! directory and the versions of their dependencies listed in `[workspace.dependencies]`.
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use toml_edit::{value, Array, DocumentMut, InlineTable, Item, Table};
+
+pub use puzzle_input::MANIFEST_FILE_NAME;
+const LOCK_FILE_NAME: &str = "Cargo.lock";
+const DEPENDENCY_TABLES: [&str; 2] = ["dependencies", "dev-dependencies"];
+
+fn read_manifest(path: &Path) -> io::Result<DocumentMut> {
+    fs::read_to_string(path)?.parse().map_err(|err| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("{}: {err}", path.display()),
+        )
+    })
+}
+
+/// Orders the version requirements by their numbers, `"1"` < `"1.11.1"` < `"^2"`
+fn compare_versions(a: &str, b: &str) -> Ordering {
+    let numbers = |version: &str| -> Vec<u64> {
+        version
+            .trim_start_matches(['^', '~', '=', ' '])
+            .split('.')
+            .map_while(|part| part.parse().ok())
+            .collect()
+    };
+    numbers(a).cmp(&numbers(b))
+}
+
+/// Version of a dependency written as `"1.0"` or `{ version = "1.0", ... }`
+fn dependency_version(item: &Item) -> Option<&str> {
+    item.as_str()
+        .or_else(|| item.as_table_like()?.get("version")?.as_str())
+}
+
+/// Name in the `[package]` table of the manifest
+fn package_name(manifest: &DocumentMut) -> Option<&str> {
+    manifest.get("package")?.get("name")?.as_str()
+}
+
+/// `dayN` or `test_dayN`, the directories created by `start_coding`
+pub fn is_day_package(name: &str) -> bool {
+    let day = name.strip_prefix("test_").unwrap_or(name);
+    day.strip_prefix("day")
+        .is_some_and(|n| !n.is_empty() && n.chars().all(|c| c.is_ascii_digit()))
+}
+
+pub struct Workspace {
+    root: PathBuf,
+    manifest: DocumentMut,
+}
+
+impl Workspace {
+    /// An empty workspace in the root directory, not saved yet
+    pub fn new(root: &Path) -> Self {
+        let mut workspace = Table::new();
+        workspace.insert("resolver", value("2"));
+        workspace.insert("members", value(Array::new()));
+        workspace.insert("dependencies", Item::Table(Table::new()));
+
+        let mut manifest = DocumentMut::new();
+        manifest.insert("workspace", Item::Table(workspace));
+        Self {
+            root: root.to_path_buf(),
+            manifest,
+        }
+    }
+
+    /// Opens the workspace of the root directory, `None` if it has no `Cargo.toml`
+    pub fn open(root: &Path) -> io::Result<Option<Self>> {
+        let path = root.join(MANIFEST_FILE_NAME);
+        if !path.exists() {
+            return Ok(None);
+        }
+        let manifest = read_manifest(&path)?;
+        if !manifest.contains_table("workspace") {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("{} is not a workspace", path.display()),
+            ));
+        }
+        Ok(Some(Self {
+            root: root.to_path_buf(),
+            manifest,
+        }))
+    }
+
+    pub fn members(&self) -> Vec<&str> {
+        self.array("members")
+    }
+
+    pub fn excluded(&self) -> Vec<&str> {
+        self.array("exclude")
+    }
+
+    fn array(&self, key: &str) -> Vec<&str> {
+        self.manifest["workspace"]
+            .get(key)
+            .and_then(Item::as_array)
+            .map(|array| array.iter().filter_map(|m| m.as_str()).collect())
+            .unwrap_or_default()
+    }
+
+    fn workspace_mut(&mut self) -> &mut Table {
+        self.manifest["workspace"].as_table_mut().unwrap()
+    }
+
+    fn array_mut(&mut self, key: &str) -> &mut Array {
+        let item = self
+            .workspace_mut()
+            .entry(key)
+            .or_insert(value(Array::new()));
+        if !item.is_array() {
+            *item = value(Array::new());
+        }
+        item.as_array_mut().unwrap()
+    }
+
+    fn dependencies_mut(&mut self) -> &mut Table {
+        let item = self
+            .workspace_mut()
+            .entry("dependencies")
+            .or_insert(Item::Table(Table::new()));
+        if !item.is_table() {
+            *item = Item::Table(Table::new());
+        }
+        item.as_table_mut().unwrap()
+    }
+
+    pub fn dependency(&self, name: &str) -> Option<&str> {
+        dependency_version(self.manifest["workspace"]["dependencies"].get(name)?)
+    }
+
+    /// Shares the version of the dependency, keeping the higher one if it is already shared
+    pub fn share_dependency(&mut self, name: &str, version: &str) {
+        let shared = self.dependency(name);
+        if shared.is_none_or(|shared| compare_versions(shared, version) == Ordering::Less) {
+            self.dependencies_mut().insert(name, value(version));
+        }
+    }
+
+    /// Keeps the package directory out of the workspace, for packages that are not members
+    pub fn exclude(&mut self, dir: &str) {
+        let exclude = self.array_mut("exclude");
+        if !exclude.iter().any(|d| d.as_str() == Some(dir)) {
+            exclude.push(dir);
+        }
+    }
+
+    /// Adds the package in `member`, relative to the root, and makes its dependencies use the
+    /// shared versions. Returns `false` if it was already a member.
+    pub fn add_member(&mut self, member: &str) -> io::Result<bool> {
+        let package_dir = self.root.join(member);
+        let path = package_dir.join(MANIFEST_FILE_NAME);
+        let mut manifest = read_manifest(&path)?;
+
+        for table in DEPENDENCY_TABLES {
+            let Some(dependencies) = manifest.get_mut(table).and_then(|t| t.as_table_like_mut())
+            else {
+                continue;
+            };
+            for (name, item) in dependencies.iter_mut() {
+                let Some(version) = dependency_version(item).map(str::to_owned) else {
+                    // path, git and workspace dependencies are left as they are
+                    continue;
+                };
+                self.share_dependency(&name, &version);
+
+                if let Some(table) = item.as_table_like_mut() {
+                    table.remove("version");
+                    table.insert("workspace", value(true));
+                    if let Some(table) = item.as_inline_table_mut() {
+                        table.fmt();
+                    }
+                } else {
+                    let mut table = InlineTable::new();
+                    table.insert("workspace", true.into());
+                    *item = value(table);
+                }
+            }
+        }
+        fs::write(&path, manifest.to_string())?;
+
+        // the lock file of the workspace is used instead
+        match fs::remove_file(package_dir.join(LOCK_FILE_NAME)) {
+            Err(err) if err.kind() != io::ErrorKind::NotFound => return Err(err),
+            _ => {}
+        }
+
+        if let Some(exclude) = self
+            .workspace_mut()
+            .get_mut("exclude")
+            .and_then(Item::as_array_mut)
+        {
+            exclude.retain(|dir| dir.as_str() != Some(member));
+        }
+        if self.members().contains(&member) {
+            return Ok(false);
+        }
+        self.array_mut("members").push(member);
+        Ok(true)
+    }
+
+    /// Writes one member per line
+    fn format_arrays(&mut self) {
+        for key in ["members", "exclude"] {
+            if let Some(array) = self
+                .workspace_mut()
+                .get_mut(key)
+                .and_then(Item::as_array_mut)
+            {
+                for member in array.iter_mut() {
+                    member.decor_mut().set_prefix("\n    ");
+                    member.decor_mut().set_suffix("");
+                }
+                array.set_trailing("\n");
+                array.set_trailing_comma(true);
+            }
+        }
+    }
+
+    pub fn save(&mut self) -> io::Result<()> {
+        self.format_arrays();
+        fs::write(
+            self.root.join(MANIFEST_FILE_NAME),
+            self.manifest.to_string(),
+        )
+    }
+}
+
+/// Finds the day packages in the root and in its year directories, and the other packages in
+/// the root
+fn find_packages(root: &Path) -> io::Result<(Vec<String>, Vec<String>)> {
+    let mut days = vec![];
+    let mut others = vec![];
+    for entry in fs::read_dir(root)? {
+        let entry = entry?;
+        let name = entry.file_name().to_string_lossy().into_owned();
+        if !entry.file_type()?.is_dir() {
+            continue;
+        }
+
+        if is_day_package(&name) && entry.path().join(MANIFEST_FILE_NAME).exists() {
+            days.push(name);
+        } else if entry.path().join(MANIFEST_FILE_NAME).exists() {
+            others.push(name);
+        } else if name.len() == 4 && name.chars().all(|c| c.is_ascii_digit()) {
+            for day in fs::read_dir(entry.path())? {
+                let day = day?;
+                let day_name = day.file_name().to_string_lossy().into_owned();
+                if is_day_package(&day_name) && day.path().join(MANIFEST_FILE_NAME).exists() {
+                    days.push(format!("{name}/{day_name}"));
+                }
+            }
+        }
+    }
+    // day2 comes before day10
+    days.sort_by_key(|day| {
+        let number = day.len() - day.trim_end_matches(|c: char| c.is_ascii_digit()).len();
+        let (name, number) = day.split_at(day.len() - number);
+        (name.to_owned(), number.parse::<u32>().unwrap_or_default())
+    });
+    others.sort();
+    Ok((days, others))
+}
+
+/// Creates the workspace of the root with only the member. The other packages of the root are
+/// excluded and left as they are, `migrate` turns the days into members later.
+pub fn create(root: &Path, member: &str) -> io::Result<Workspace> {
+    let mut workspace = Workspace::new(root);
+    let (days, others) = find_packages(root)?;
+    for dir in days.iter().chain(&others).filter(|dir| *dir != member) {
+        workspace.exclude(dir);
+    }
+    workspace.add_member(member)?;
+    workspace.save()?;
+    Ok(workspace)
+}
+
+/// Turns the day packages of the root into members of its workspace, creating it if needed.
+/// The highest version of each dependency is shared by every member. The other packages in
+/// the root, like `start_coding`, are excluded from the workspace. Fails without changing
+/// anything if two days have the same package name, which cargo refuses in a workspace.
+pub fn migrate(root: &Path) -> io::Result<Workspace> {
+    let mut workspace = match Workspace::open(root)? {
+        Some(workspace) => workspace,
+        None => Workspace::new(root),
+    };
+    let (days, others) = find_packages(root)?;
+
+    // the versions are shared before any member is converted, so that all of them use the
+    // highest one
+    let mut names: HashMap<String, &str> = HashMap::new();
+    for day in &days {
+        let manifest = read_manifest(&root.join(day).join(MANIFEST_FILE_NAME))?;
+        if let Some(name) = package_name(&manifest) {
+            if let Some(other) = names.insert(name.to_owned(), day) {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!(
+                        "'{other}' and '{day}' are both named '{name}', rename one of the \
+                         packages, e.g. to 'aoc2024-day1'"
+                    ),
+                ));
+            }
+        }
+        for table in DEPENDENCY_TABLES {
+            if let Some(dependencies) = manifest.get(table).and_then(|t| t.as_table_like()) {
+                for (name, item) in dependencies.iter() {
+                    if let Some(version) = dependency_version(item) {
+                        workspace.share_dependency(name, version);
+                    }
+                }
+            }
+        }
+    }
+    for day in &days {
+        workspace.add_member(day)?;
+    }
+    for other in &others {
+        workspace.exclude(other);
+    }
+    workspace.save()?;
+    Ok(workspace)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compare_versions() {
+        assert_eq!(compare_versions("1", "1.11.1"), Ordering::Less);
+        assert_eq!(compare_versions("^2", "1.11.1"), Ordering::Greater);
+        assert_eq!(compare_versions("0.1.12", "=0.1.12"), Ordering::Equal);
+        assert_eq!(compare_versions("*", "0.1"), Ordering::Less);
+    }
+
+    #[test]
+    fn test_is_day_package() {
+        assert!(is_day_package("day1"));
+        assert!(is_day_package("test_day25"));
+        assert!(!is_day_package("day"));
+        assert!(!is_day_package("start_coding"));
+    }
+
+    #[test]
+    fn test_migrate() {
+        let root =
+            std::env::temp_dir().join(format!("start_coding_workspace_{}", std::process::id()));
+        let package = |dir: &str, dependencies: &str| {
+            let name = dir.replace('/', "-");
+            let dir = root.join(dir);
+            fs::create_dir_all(&dir).unwrap();
+            fs::write(
+                dir.join(MANIFEST_FILE_NAME),
+                format!("[package]\nname = \"{name}\"\n\n[dependencies]\n{dependencies}"),
+            )
+            .unwrap();
+            fs::write(dir.join(LOCK_FILE_NAME), "").unwrap();
+        };
+        package("day1", "text_io = \"0.1.12\"\nregex = \"1\"\n");
+        package(
+            "day3",
+            "regex = { version = \"1.11.1\", features = [\"std\"] }\n",
+        );
+        package("2024/day15", "text_io = \"0.1.12\"\n");
+        package("start_coding", "clap = \"4\"\n");
+
+        let workspace = migrate(&root).unwrap();
+        assert_eq!(workspace.members(), ["2024/day15", "day1", "day3"]);
+        assert_eq!(workspace.dependency("regex"), Some("1.11.1"));
+        assert_eq!(workspace.dependency("text_io"), Some("0.1.12"));
+        assert_eq!(workspace.dependency("clap"), None);
+
+        let day3 = fs::read_to_string(root.join("day3").join(MANIFEST_FILE_NAME)).unwrap();
+        assert!(day3.contains("regex = { features = [\"std\"], workspace = true }"));
+        assert!(!root.join("day1").join(LOCK_FILE_NAME).exists());
+
+        let mut workspace = Workspace::open(&root).unwrap().unwrap();
+        assert!(!workspace.add_member("day1").unwrap());
+        let manifest = fs::read_to_string(root.join(MANIFEST_FILE_NAME)).unwrap();
+        assert!(manifest.contains("exclude = [\n    \"start_coding\",\n]"));
+
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn test_create() {
+        let root = std::env::temp_dir().join(format!(
+            "start_coding_workspace_create_{}",
+            std::process::id()
+        ));
+        let package = |dir: &str| {
+            let name = dir.replace('/', "-");
+            let dir = root.join(dir);
+            fs::create_dir_all(&dir).unwrap();
+            fs::write(
+                dir.join(MANIFEST_FILE_NAME),
+                format!("[package]\nname = \"{name}\"\n\n[dependencies]\ntext_io = \"0.1.12\"\n"),
+            )
+            .unwrap();
+            fs::write(dir.join(LOCK_FILE_NAME), "").unwrap();
+        };
+        package("day1");
+        package("start_coding");
+        package("2024/day2");
+
+        let workspace = create(&root, "2024/day2").unwrap();
+        assert_eq!(workspace.members(), ["2024/day2"]);
+        assert_eq!(workspace.excluded(), ["day1", "start_coding"]);
+        let day1 = fs::read_to_string(root.join("day1").join(MANIFEST_FILE_NAME)).unwrap();
+        assert!(day1.contains("text_io = \"0.1.12\""));
+        assert!(root.join("day1").join(LOCK_FILE_NAME).exists());
+        assert!(!root.join("2024/day2").join(LOCK_FILE_NAME).exists());
+
+        let workspace = migrate(&root).unwrap();
+        assert_eq!(workspace.members(), ["2024/day2", "day1"]);
+        assert_eq!(workspace.excluded(), ["start_coding"]);
+
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn test_migrate_years() {
+        let root = std::env::temp_dir().join(format!(
+            "start_coding_workspace_years_{}",
+            std::process::id()
+        ));
+        let package = |dir: &str, name: &str| {
+            let dir = root.join(dir);
+            fs::create_dir_all(dir.join("src")).unwrap();
+            fs::write(
+                dir.join(MANIFEST_FILE_NAME),
+                format!("[package]\nname = \"{name}\"\nversion = \"0.1.0\"\nedition = \"2021\"\n"),
+            )
+            .unwrap();
+            fs::write(dir.join("src").join("lib.rs"), "").unwrap();
+        };
+        // the days of this repository before the year directories, and two years of new ones
+        package("day1", "day1");
+        package("2023/day1", "aoc2023-day1");
+        package("2024/day1", "aoc2024-day1");
+
+        let workspace = migrate(&root).unwrap();
+        assert_eq!(workspace.members(), ["2023/day1", "2024/day1", "day1"]);
+        let output = std::process::Command::new(env!("CARGO"))
+            .args([
+                "metadata",
+                "--no-deps",
+                "--offline",
+                "--format-version",
+                "1",
+            ])
+            .current_dir(&root)
+            .output()
+            .unwrap();
+        assert!(output.status.success(), "{output:?}");
+
+        package("2025/day1", "day1");
+        let manifest = fs::read_to_string(root.join(MANIFEST_FILE_NAME)).unwrap();
+        let err = migrate(&root).err().unwrap();
+        assert!(err
+            .to_string()
+            .contains("'2025/day1' and 'day1' are both named 'day1'"));
+        assert_eq!(
+            fs::read_to_string(root.join(MANIFEST_FILE_NAME)).unwrap(),
+            manifest
+        );
+
+        fs::remove_dir_all(root).unwrap();
+    }
+}
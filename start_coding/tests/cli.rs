@@ -0,0 +1,395 @@
+//! Runs the binary against a local server that plays the Advent of Code endpoints.
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+const TOKEN: &str = "test-token";
+
+const PUZZLE: &str = r#"<html><body><main>
+<article class="day-desc"><h2>--- Day 1: Not Quite Lisp ---</h2>
+<p>Santa is trying to deliver presents in a large apartment building.</p>
+<pre><code>(())</code></pre>
+<p>For example, <code>(())</code> results in floor <code><em>0</em></code>.</p>
+</article>
+</main></body></html>"#;
+
+const LEADERBOARD: &str = r#"{"owner_id":1,"event":"2015","members":{
+"1":{"id":1,"name":"Alice","stars":2,"local_score":2,"last_star_ts":1448950000,
+"completion_day_level":{"1":{"1":{"get_star_ts":1448949000,"star_index":1},
+"2":{"get_star_ts":1448950000,"star_index":2}}}}}}"#;
+
+#[derive(Debug, Clone)]
+struct Request {
+    method: String,
+    path: String,
+    body: String,
+    cookie: Option<String>,
+    user_agent: Option<String>,
+}
+
+type Routes = fn(&Request) -> (u16, String);
+
+/// Answers the requests with the routes and records them
+struct MockServer {
+    url: String,
+    requests: Arc<Mutex<Vec<Request>>>,
+}
+
+impl MockServer {
+    fn start(routes: Routes) -> Self {
+        let server = tiny_http::Server::http("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", server.server_addr().to_ip().unwrap());
+        let requests = Arc::new(Mutex::new(vec![]));
+
+        let recorded = Arc::clone(&requests);
+        thread::spawn(move || {
+            for mut incoming in server.incoming_requests() {
+                let mut body = String::new();
+                incoming.as_reader().read_to_string(&mut body).unwrap();
+                let request = Request {
+                    method: incoming.method().to_string(),
+                    path: incoming.url().to_owned(),
+                    body,
+                    cookie: header(&incoming, "Cookie"),
+                    user_agent: header(&incoming, "User-Agent"),
+                };
+
+                let (status, response) = routes(&request);
+                recorded.lock().unwrap().push(request);
+                let response = tiny_http::Response::from_string(response).with_status_code(status);
+                incoming.respond(response).unwrap();
+            }
+        });
+
+        Self { url, requests }
+    }
+
+    fn requests(&self) -> Vec<Request> {
+        self.requests.lock().unwrap().clone()
+    }
+}
+
+fn header(request: &tiny_http::Request, name: &'static str) -> Option<String> {
+    request
+        .headers()
+        .iter()
+        .find(|header| header.field.equiv(name))
+        .map(|header| header.value.to_string())
+}
+
+fn advent_of_code(request: &Request) -> (u16, String) {
+    match (request.method.as_str(), request.path.as_str()) {
+        (_, _) if request.cookie.as_deref() != Some("session=test-token") => (
+            400,
+            String::from("Puzzle inputs differ by user.  Please log in to get your puzzle input."),
+        ),
+        ("GET", "/2015/day/1/input") => (200, String::from("(()(()(\n")),
+        ("GET", "/2015/day/1") => (200, String::from(PUZZLE)),
+        ("GET", "/settings") => (
+            200,
+            String::from(r#"<div class="user">Alice <span class="star-count">2*</span></div>"#),
+        ),
+        ("GET", "/2015") => (
+            200,
+            String::from(
+                r#"<a href="/2015/day/1" class="calendar-day1 calendar-verycomplete">
+<a href="/2015/day/2" class="calendar-day2 calendar-complete">"#,
+            ),
+        ),
+        ("GET", "/2015/leaderboard/private/view/42.json") => (200, String::from(LEADERBOARD)),
+        ("POST", "/2015/day/1/answer") if request.body == "level=1&answer=3" => (
+            200,
+            String::from("<article><p>That's the right answer!</p></article>"),
+        ),
+        ("POST", "/2015/day/1/answer") => (
+            200,
+            String::from(
+                "<article><p>That's not the right answer; your answer is too high.</p></article>",
+            ),
+        ),
+        _ => (404, String::from("404 Not Found")),
+    }
+}
+
+/// A directory of its own for each test, the cache and the config are kept inside
+fn test_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("start_coding_cli_{name}_{}", std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+fn run(server: &MockServer, dir: &Path, token: &str, args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_start_coding"))
+        .args(["--session-source", "env", "--year", "2015", "--day", "1"])
+        .args(args)
+        .current_dir(dir)
+        .env("AOC_SESSION", token)
+        .env("AOC_BASE_URL", &server.url)
+        .env("XDG_CACHE_HOME", dir.join("cache"))
+        .env("XDG_CONFIG_HOME", dir.join("config"))
+        .output()
+        .unwrap()
+}
+
+#[test]
+fn test_download_input_and_puzzle() {
+    let server = MockServer::start(advent_of_code);
+    let dir = test_dir("download");
+
+    let output = run(&server, &dir, TOKEN, &["--no-workspace"]);
+    assert!(output.status.success(), "{output:?}");
+
+    let package = dir.join("2015").join("day1");
+    assert_eq!(
+        fs::read_to_string(package.join("input.txt")).unwrap(),
+        "(()(()(\n"
+    );
+    assert!(fs::read_to_string(package.join("PUZZLE.md"))
+        .unwrap()
+        .contains("Not Quite Lisp"));
+    assert_eq!(
+        fs::read_to_string(package.join("example1.txt")).unwrap(),
+        "(())"
+    );
+    assert!(fs::read_to_string(package.join("src").join("lib.rs"))
+        .unwrap()
+        .contains("\"0\""));
+    assert!(fs::read_to_string(package.join("Cargo.toml"))
+        .unwrap()
+        .contains("name = \"aoc2015-day1\""));
+
+    let requests = server.requests();
+    assert!(requests.iter().all(|request| request
+        .user_agent
+        .as_deref()
+        .unwrap()
+        .starts_with("start_coding/")));
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn test_offline_uses_the_cache() {
+    let server = MockServer::start(advent_of_code);
+    let dir = test_dir("offline");
+
+    let output = run(&server, &dir, TOKEN, &["--offline", "--no-package"]);
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("not cached"));
+    assert!(server.requests().is_empty());
+
+    assert!(run(&server, &dir, TOKEN, &["--no-package"])
+        .status
+        .success());
+    let requests = server.requests().len();
+    fs::remove_file(dir.join("2015").join("day1").join("input.txt")).unwrap();
+
+    let output = run(&server, &dir, TOKEN, &["--offline", "--no-package"]);
+    assert!(output.status.success(), "{output:?}");
+    assert_eq!(server.requests().len(), requests);
+    assert_eq!(
+        fs::read_to_string(dir.join("2015").join("day1").join("input.txt")).unwrap(),
+        "(()(()(\n"
+    );
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn test_submit_answer() {
+    let server = MockServer::start(advent_of_code);
+    let dir = test_dir("submit");
+
+    let output = run(&server, &dir, TOKEN, &["submit", "1", "5"]);
+    assert_eq!(output.status.code(), Some(10));
+    let output = run(&server, &dir, TOKEN, &["submit", "1", "7"]);
+    assert_eq!(output.status.code(), Some(10));
+    let output = run(&server, &dir, TOKEN, &["submit", "1", "3"]);
+    assert_eq!(output.status.code(), Some(0));
+
+    // 7 is above the answer that was too high, so it is refused without a request
+    let answers: Vec<String> = server
+        .requests()
+        .into_iter()
+        .filter(|request| request.method == "POST")
+        .map(|request| request.body)
+        .collect();
+    assert_eq!(answers, ["level=1&answer=5", "level=1&answer=3"]);
+
+    // the directory made for the answers doesn't stop the package from being created
+    let output = run(&server, &dir, TOKEN, &["--no-workspace"]);
+    assert!(output.status.success(), "{output:?}");
+    let package = dir.join("2015").join("day1");
+    assert!(package.join("Cargo.toml").is_file());
+    assert!(package.join("answers.json").is_file());
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn test_expired_session() {
+    let server = MockServer::start(advent_of_code);
+    let dir = test_dir("expired");
+
+    let output = run(&server, &dir, "old-token", &["--no-package"]);
+    assert_eq!(output.status.code(), Some(1));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("The session has expired"));
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn test_local_config() {
+    let server = MockServer::start(advent_of_code);
+    let dir = test_dir("config");
+    fs::write(
+        dir.join(".start_coding.toml"),
+        "file-name = \"data\"\noutput-root = \"solutions\"\n",
+    )
+    .unwrap();
+
+    let output = run(&server, &dir, TOKEN, &["--no-package"]);
+    assert!(output.status.success(), "{output:?}");
+    let package = dir.join("solutions").join("2015").join("day1");
+    assert!(package.join("data.txt").exists());
+
+    // the command line wins over the config
+    let output = run(&server, &dir, TOKEN, &["--no-package", "-f", "input"]);
+    assert!(output.status.success(), "{output:?}");
+    assert!(package.join("input.txt").exists());
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn test_leaderboard() {
+    let server = MockServer::start(advent_of_code);
+    let dir = test_dir("leaderboard");
+
+    let output = run(&server, &dir, TOKEN, &["leaderboard", "42"]);
+    assert!(output.status.success(), "{output:?}");
+    let table = String::from_utf8_lossy(&output.stdout);
+    assert!(table.contains("   1  Alice      2      2  *........................"));
+
+    // the JSON is cached for 15 minutes
+    let output = run(&server, &dir, TOKEN, &["leaderboard", "42", "--json"]);
+    assert_eq!(String::from_utf8_lossy(&output.stdout), LEADERBOARD);
+    assert_eq!(server.requests().len(), 1);
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn test_status() {
+    let server = MockServer::start(advent_of_code);
+    let dir = test_dir("status");
+    assert!(run(&server, &dir, TOKEN, &["--no-workspace"])
+        .status
+        .success());
+
+    let output = run(&server, &dir, TOKEN, &["status"]);
+    assert!(output.status.success(), "{output:?}");
+    let status = String::from_utf8_lossy(&output.stdout);
+    assert!(status.contains("  1  yes      yes     yes    **     -"));
+    assert!(status.contains("  2  -        -       -      *      -"));
+    assert!(status.contains("  3  -        -       -             -"));
+    assert!(status.contains("3 stars"));
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn test_whoami() {
+    let server = MockServer::start(advent_of_code);
+    let dir = test_dir("whoami");
+
+    let output = run(&server, &dir, TOKEN, &["whoami"]);
+    assert!(output.status.success(), "{output:?}");
+    assert_eq!(
+        String::from_utf8_lossy(&output.stdout),
+        "Logged in as Alice (session from the AOC_SESSION environment variable)\n"
+    );
+
+    let output = run(&server, &dir, "old-token", &["whoami"]);
+    assert_eq!(output.status.code(), Some(1));
+    assert!(String::from_utf8_lossy(&output.stderr)
+        .contains("The session from the AOC_SESSION environment variable has expired"));
+
+    let output = run(&server, &dir, "", &["whoami"]);
+    assert_eq!(output.status.code(), Some(1));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("No session found"));
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn test_edited_input() {
+    let server = MockServer::start(advent_of_code);
+    let dir = test_dir("edited");
+    let input = dir.join("2015").join("day1").join("input.txt");
+
+    let output = run(
+        &server,
+        &dir,
+        TOKEN,
+        &["--no-package", "--trailing-newline", "strip"],
+    );
+    assert!(output.status.success(), "{output:?}");
+    assert_eq!(fs::read_to_string(&input).unwrap(), "(()(()(");
+    assert!(
+        fs::read_to_string(dir.join("2015").join("day1").join("input.txt.sha256"))
+            .unwrap()
+            .ends_with("  input.txt\n")
+    );
+
+    // the question is answered with no when stdin is closed
+    fs::write(&input, "edited").unwrap();
+    let output = run(&server, &dir, TOKEN, &["--no-package"]);
+    assert!(String::from_utf8_lossy(&output.stdout).contains("was changed since it was downloaded"));
+    assert_eq!(fs::read_to_string(&input).unwrap(), "edited");
+
+    let output = run(&server, &dir, TOKEN, &["--no-package", "--yes"]);
+    assert!(output.status.success(), "{output:?}");
+    assert_eq!(fs::read_to_string(&input).unwrap(), "(()(()(\n");
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn test_encrypted_input() {
+    let server = MockServer::start(advent_of_code);
+    let dir = test_dir("encrypted");
+    let package = dir.join("2015").join("day1");
+    fs::write(dir.join("key"), "secret\n").unwrap();
+
+    let output = run(
+        &server,
+        &dir,
+        TOKEN,
+        &["--no-package", "--encrypt", "--key-file", "key"],
+    );
+    assert!(output.status.success(), "{output:?}");
+    assert!(package.join("input.txt.enc").is_file());
+    assert!(!package.join("input.txt").exists());
+    assert!(fs::read_to_string(dir.join(".gitignore"))
+        .unwrap()
+        .contains("\ninput.txt\n"));
+
+    let output = run(&server, &dir, TOKEN, &["--key-file", "key", "decrypt"]);
+    assert!(output.status.success(), "{output:?}");
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "(()(()(\n");
+
+    let output = run(&server, &dir, TOKEN, &["decrypt"]);
+    assert_eq!(output.status.code(), Some(1));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("the input is encrypted"));
+
+    // The command line overrides `encrypt = true` in the config
+    fs::write(
+        dir.join(".start_coding.toml"),
+        "encrypt = true\nkey-file = \"key\"\n",
+    )
+    .unwrap();
+    fs::remove_file(package.join("input.txt.enc")).unwrap();
+    let output = run(&server, &dir, TOKEN, &["--no-package", "--no-encrypt"]);
+    assert!(output.status.success(), "{output:?}");
+    assert_eq!(
+        fs::read_to_string(package.join("input.txt")).unwrap(),
+        "(()(()(\n"
+    );
+    assert!(!package.join("input.txt.enc").exists());
+    fs::remove_dir_all(dir).unwrap();
+}